@@ -445,6 +445,47 @@ mod tests {
         println!("{}", output);
     }
 
+    #[test]
+    fn test_commit_bits_le() {
+        use snarkvm_console_types::prelude::{TestRng, Uniform};
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..10 {
+                // Sample a random element and randomizer.
+                let expected = snarkvm_console_types::Field::<<CurrentAleo as Environment>::Network>::rand(&mut rng);
+                let given_bits = expected.to_bits_le().iter().map(|bit| Boolean::new(mode, *bit)).collect::<Vec<_>>();
+                let randomizer = Scalar::new(mode, Uniform::rand(&mut rng));
+
+                // Commit to the bits, and ensure the field element is reconstructed.
+                let (candidate, commitment) =
+                    Field::<CurrentAleo>::commit_bits_le(&given_bits, &randomizer, CurrentAleo::commit_bhp256);
+                assert_eq!(expected, candidate.eject_value());
+
+                // Ensure the commitment opens to the revealed field element.
+                let opening = CurrentAleo::commit_bhp256(&candidate.to_bits_le(), &randomizer);
+                assert_eq!(commitment.eject_value(), opening.eject_value());
+
+                // Ensure the commitment does not open to a different field element, or with a different randomizer.
+                let other = Field::<CurrentAleo>::new(mode, Uniform::rand(&mut rng));
+                let opening = CurrentAleo::commit_bhp256(&other.to_bits_le(), &randomizer);
+                assert_ne!(commitment.eject_value(), opening.eject_value());
+                let other_randomizer = Scalar::new(mode, Uniform::rand(&mut rng));
+                let opening = CurrentAleo::commit_bhp256(&candidate.to_bits_le(), &other_randomizer);
+                assert_ne!(commitment.eject_value(), opening.eject_value());
+
+                // Ensure the commitment scheme is interchangeable.
+                let (_, commitment) =
+                    Field::<CurrentAleo>::commit_bits_le(&given_bits, &randomizer, CurrentAleo::commit_bhp512);
+                let opening = CurrentAleo::commit_bhp512(&candidate.to_bits_le(), &randomizer);
+                assert_eq!(commitment.eject_value(), opening.eject_value());
+            }
+        }
+        assert!(CurrentAleo::is_satisfied());
+        CurrentAleo::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         CurrentAleo::scope("test_circuit_scope", || {
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Boolean<E> {
+    /// Enforces that `self` is `true`.
+    ///
+    /// This costs one constraint if `self` is not a constant.
+    /// If `self` is a constant, the assertion is checked directly, and a constant `false` halts.
+    pub fn enforce_true(&self) {
+        E::assert(self)
+    }
+
+    /// Enforces that `self` is `false`.
+    ///
+    /// This costs one constraint if `self` is not a constant.
+    /// If `self` is a constant, the assertion is checked directly, and a constant `true` halts.
+    pub fn enforce_false(&self) {
+        E::assert_eq(E::zero(), self)
+    }
+}
+
+/// The operation of `Boolean::enforce_true`, which keys its `Metrics` and `OutputMode`.
+pub trait EnforceTrue {}
+
+/// The operation of `Boolean::enforce_false`, which keys its `Metrics` and `OutputMode`.
+pub trait EnforceFalse {}
+
+impl<E: Environment> Metrics<dyn EnforceTrue> for Boolean<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 0, 1),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn EnforceTrue> for Boolean<E> {
+    type Case = Mode;
+
+    /// The enforcement does not allocate an output, so its (empty) output is constant.
+    fn output_mode(_case: &Self::Case) -> Mode {
+        Mode::Constant
+    }
+}
+
+impl<E: Environment> Metrics<dyn EnforceFalse> for Boolean<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 0, 1),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn EnforceFalse> for Boolean<E> {
+    type Case = Mode;
+
+    /// The enforcement does not allocate an output, so its (empty) output is constant.
+    fn output_mode(_case: &Self::Case) -> Mode {
+        Mode::Constant
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_enforce(name: &str, mode: Mode, value: bool) {
+        // Ensure `enforce_true` is satisfied if and only if the boolean is `true`.
+        if !mode.is_constant() || value {
+            let candidate = Boolean::<Circuit>::new(mode, value);
+            Circuit::scope(format!("{name} enforce_true"), || {
+                candidate.enforce_true();
+                match value {
+                    true => assert_count!(Boolean<Circuit>, EnforceTrue, &mode),
+                    false => assert_count_fails!(Boolean<Circuit>, EnforceTrue, &mode),
+                }
+            });
+            assert_eq!(value, Circuit::is_satisfied());
+            Circuit::reset();
+        }
+
+        // Ensure `enforce_false` is satisfied if and only if the boolean is `false`.
+        if !mode.is_constant() || !value {
+            let candidate = Boolean::<Circuit>::new(mode, value);
+            Circuit::scope(format!("{name} enforce_false"), || {
+                candidate.enforce_false();
+                match value {
+                    true => assert_count_fails!(Boolean<Circuit>, EnforceFalse, &mode),
+                    false => assert_count!(Boolean<Circuit>, EnforceFalse, &mode),
+                }
+            });
+            assert_eq!(!value, Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_enforce_constant() {
+        check_enforce("Constant true", Mode::Constant, true);
+        check_enforce("Constant false", Mode::Constant, false);
+    }
+
+    #[test]
+    fn test_enforce_public() {
+        check_enforce("Public true", Mode::Public, true);
+        check_enforce("Public false", Mode::Public, false);
+    }
+
+    #[test]
+    fn test_enforce_private() {
+        check_enforce("Private true", Mode::Private, true);
+        check_enforce("Private false", Mode::Private, false);
+    }
+
+    #[test]
+    fn test_enforce_count() {
+        // Ensure the counts match the constraints of `enforce_true` and `enforce_false`.
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = Boolean::<Circuit>::new(mode, true);
+            Circuit::scope(format!("{mode} enforce_true"), || {
+                candidate.enforce_true();
+                match mode.is_constant() {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, 0, 1),
+                }
+            });
+            let candidate = Boolean::<Circuit>::new(mode, false);
+            Circuit::scope(format!("{mode} enforce_false"), || {
+                candidate.enforce_false();
+                match mode.is_constant() {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, 0, 1),
+                }
+            });
+            assert_eq!(Mode::Constant, output_mode!(Boolean<Circuit>, EnforceTrue, &mode));
+            assert_eq!(Mode::Constant, output_mode!(Boolean<Circuit>, EnforceFalse, &mode));
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_enforce_false_on_constant_true_fails() {
+        Boolean::<Circuit>::constant(true).enforce_false();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_enforce_true_on_constant_false_fails() {
+        Boolean::<Circuit>::constant(false).enforce_true();
+    }
+}
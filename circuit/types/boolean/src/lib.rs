@@ -19,6 +19,7 @@
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
 mod helpers;
+pub use helpers::enforce::{EnforceFalse, EnforceTrue};
 
 pub mod and;
 pub mod equal;
@@ -30,7 +31,14 @@ pub mod ternary;
 pub mod xor;
 
 #[cfg(test)]
-use snarkvm_circuit_environment::{assert_count, assert_output_mode, assert_scope, count, output_mode};
+use snarkvm_circuit_environment::{
+    assert_count,
+    assert_count_fails,
+    assert_output_mode,
+    assert_scope,
+    count,
+    output_mode,
+};
 
 use snarkvm_circuit_environment::prelude::*;
 
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Adds the base field element represented by the given **little-endian** bits to `acc`, in place,
+    /// as in `*acc += Field::from_bits_le(bits_le)`.
+    ///
+    /// The bits are range checked as in `from_bits_le`. As the reconstruction is a linear combination
+    /// of the bits, it is folded into `acc` without allocating an intermediate variable, and the addition is free.
+    pub fn add_bits_le_into(acc: &mut Field<E>, bits_le: &[Boolean<E>]) {
+        *acc += Self::from_bits_le(bits_le);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_add_bits_le_into(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let acc: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, given).to_bits_le();
+
+            // Ensure the value and cost match the unfused accumulation.
+            let (candidate, candidate_counts) = Circuit::scope(format!("{mode} {i} fused"), || {
+                let mut candidate = Field::<Circuit>::new(mode, acc);
+                Field::<Circuit>::add_bits_le_into(&mut candidate, &given_bits);
+                (candidate, (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope()))
+            });
+            let (expected, expected_counts) = Circuit::scope(format!("{mode} {i} unfused"), || {
+                let mut expected = Field::<Circuit>::new(mode, acc);
+                expected += Field::<Circuit>::from_bits_le(&given_bits);
+                (expected, (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope()))
+            });
+            assert_eq!(acc + given, candidate.eject_value());
+            assert_eq!(expected.eject_value(), candidate.eject_value());
+            assert_eq!(expected_counts, candidate_counts);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_add_bits_le_into() {
+        check_add_bits_le_into(Mode::Constant);
+        check_add_bits_le_into(Mode::Public);
+        check_add_bits_le_into(Mode::Private);
+    }
+}
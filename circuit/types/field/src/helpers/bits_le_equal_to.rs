@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Returns `true` if the given **little-endian** bits reconstruct to the given constant `value`.
+    ///
+    /// Rather than reconstructing the field element (with its range check) and comparing it to `value`,
+    /// this method computes the Hamming distance between `bits_le` and the bits of `value` as a linear combination,
+    /// and checks that the distance is zero. As the distance is at most the number of bits, which is far less than
+    /// the modulus, it can not wrap around, and thus is zero if and only if every bit matches.
+    /// Note that `value` is a valid field element, so a match implies the bits are in the field.
+    ///
+    /// This method costs 2 private variables and 3 constraints, for non-constant bits.
+    pub fn bits_le_equal_to(bits_le: &[Boolean<E>], value: E::BaseField) -> Boolean<E> {
+        // Retrieve the bits of the value.
+        let value_bits_le = value.to_bits_le();
+
+        // Compute the Hamming distance between `bits_le` and the bits of `value`.
+        // Note: Any bits beyond the length of either list are treated as `false`.
+        let mut distance = Field::zero();
+        for i in 0..bits_le.len().max(value_bits_le.len()) {
+            let bit = bits_le.get(i).cloned().unwrap_or_else(|| Boolean::constant(false));
+            match value_bits_le.get(i) {
+                Some(true) => distance += Field::from_boolean(&!bit),
+                _ => distance += Field::from_boolean(&bit),
+            }
+        }
+
+        // Return `true` if the distance is zero.
+        match distance.is_constant() {
+            true => Boolean::constant(distance.eject_value().is_zero()),
+            false => distance.is_equal(&Field::zero()),
+        }
+    }
+}
+
+/// The operation of `Field::bits_le_equal_to`, which keys its `Metrics` and `OutputMode`.
+pub trait BitsLEEqualTo {}
+
+impl<E: Environment> Metrics<dyn BitsLEEqualTo> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        // The Hamming distance is a linear combination, and is free. Only the zero check incurs a cost.
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 2, 3),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn BitsLEEqualTo> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_bits_le_equal_to(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample two random elements.
+            let value: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let other: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, value).to_bits_le();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Field::<Circuit>::bits_le_equal_to(&given_bits, *value);
+                assert!(candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                let case = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+                assert_count!(Field<Circuit>, BitsLEEqualTo, &case);
+                assert_output_mode!(Field<Circuit>, BitsLEEqualTo, &case, candidate);
+            });
+
+            Circuit::scope(format!("{mode} {i} (other)"), || {
+                let candidate = Field::<Circuit>::bits_le_equal_to(&given_bits, *other);
+                assert_eq!(value == other, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                let case = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+                assert_count!(Field<Circuit>, BitsLEEqualTo, &case);
+                assert_output_mode!(Field<Circuit>, BitsLEEqualTo, &case, candidate);
+            });
+
+            // Ensure excess zero bits are permitted, and excess nonzero bits are not.
+            let candidate = vec![given_bits.clone(), vec![Boolean::new(mode, false); i as usize]].concat();
+            assert!(Field::<Circuit>::bits_le_equal_to(&candidate, *value).eject_value());
+            let candidate = vec![given_bits.clone(), vec![Boolean::new(mode, true)]].concat();
+            assert!(!Field::<Circuit>::bits_le_equal_to(&candidate, *value).eject_value());
+
+            // Ensure a truncated list of bits only matches if the truncated bits of the value are zero.
+            let num_bits = i as usize;
+            let candidate = Field::<Circuit>::bits_le_equal_to(&given_bits[..num_bits], *value);
+            assert_eq!(value.to_bits_le()[num_bits..].iter().all(|bit| !bit), candidate.eject_value());
+
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_bits_le_equal_to_constant() {
+        check_bits_le_equal_to(Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_bits_le_equal_to_public() {
+        check_bits_le_equal_to(Mode::Public, 0, 0, 2, 3);
+    }
+
+    #[test]
+    fn test_bits_le_equal_to_private() {
+        check_bits_le_equal_to(Mode::Private, 0, 0, 2, 3);
+    }
+
+    /// This test shows that `bits_le_equal_to` is more cost-effective than reconstructing the field element.
+    #[test]
+    fn test_bits_le_equal_to_matches() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random element.
+                let value: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let given_bits = Field::<Circuit>::new(mode, value).to_bits_le();
+
+                let (num_private, num_constraints) = Circuit::scope(format!("BitsLEEqualTo {mode} {i}"), || {
+                    let candidate = Field::<Circuit>::bits_le_equal_to(&given_bits, *value);
+                    assert!(candidate.eject_value());
+                    assert_scope!(0, 0, 2, 3);
+                    (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+                });
+
+                Circuit::scope(format!("Reconstruct {mode} {i}"), || {
+                    let candidate = Field::<Circuit>::from_bits_le(&given_bits).is_equal(&Field::constant(value));
+                    assert!(candidate.eject_value());
+                    assert_scope!(0, 0, 254, 256);
+
+                    // Ensure `bits_le_equal_to` uses fewer variables and constraints.
+                    assert!(num_private < Circuit::num_private_in_scope());
+                    assert!(num_constraints < Circuit::num_constraints_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+}
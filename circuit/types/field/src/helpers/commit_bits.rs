@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, as in `from_bits_le`,
+    /// and returns it along with a commitment to its bits, under the given commitment scheme and randomizer.
+    ///
+    /// The commitment is to the `E::BaseField::size_in_bits()` bits of the field element (i.e. `to_bits_le`),
+    /// which are cached by the reconstruction, so that it opens to the field element once it is revealed,
+    /// regardless of the length of `bits_le`. As the commitment schemes are defined over the circuit types,
+    /// the scheme is given as a function, e.g. `Aleo::commit_bhp256`.
+    pub fn commit_bits_le<R, C>(
+        bits_le: &[Boolean<E>],
+        randomizer: &R,
+        commit: impl FnOnce(&[Boolean<E>], &R) -> C,
+    ) -> (Self, C) {
+        // Reconstruct the field element.
+        let output = Self::from_bits_le(bits_le);
+        // Commit to the bits of the field element.
+        let commitment = commit(&output.to_bits_le(), randomizer);
+        (output, commitment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_commit_bits_le(mode: Mode) {
+        let mut rng = TestRng::default();
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element, and truncate its bits to the data size, or extend them with excess zero bits.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let mut bits = expected.to_bits_le();
+            bits.truncate(size_in_data_bits);
+            let expected = console::Field::from_bits_le(&bits).unwrap();
+            bits.resize(size_in_data_bits + (i as usize % 4), false);
+            let given_bits = bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                // Commit to the bits, recording the committed bits and randomizer.
+                let randomizer = i;
+                let (candidate, (committed_bits, committed_randomizer)) =
+                    Field::<Circuit>::commit_bits_le(&given_bits, &randomizer, |bits, randomizer| {
+                        (bits.eject_value(), *randomizer)
+                    });
+                assert_eq!(expected, candidate.eject_value());
+
+                // Ensure the commitment is to the full-width bits of the field element.
+                assert_eq!(size_in_bits, committed_bits.len());
+                assert_eq!(candidate.to_bits_le().eject_value(), committed_bits);
+                assert_eq!(randomizer, committed_randomizer);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_commit_bits_le_constant() {
+        check_commit_bits_le(Mode::Constant);
+    }
+
+    #[test]
+    fn test_commit_bits_le_public() {
+        check_commit_bits_le(Mode::Public);
+    }
+
+    #[test]
+    fn test_commit_bits_le_private() {
+        check_commit_bits_le(Mode::Private);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Enforces that the given **little-endian** bits reconstruct to the given `value`, as in `from_bits_le`.
+    ///
+    /// Rather than allocating a fresh field element and comparing it to `value`, the linear combination
+    /// of the bits is linked to `value` with a single equality constraint. Thus, this method costs
+    /// the same as `from_bits_le` (i.e. the range check, if any), plus one constraint.
+    ///
+    /// If `value` does not yet have a cached bit representation, the given bits are cached,
+    /// so that subsequent calls to `value.to_bits_le()` are free.
+    pub fn enforce_bits_le(value: &Field<E>, bits_le: &[Boolean<E>]) {
+        // Reconstruct the linear combination of the bits, with its range check.
+        let candidate = Self::from_bits_le(bits_le);
+        // Ensure `value` is equal to the reconstruction.
+        E::assert_eq(value, &candidate);
+
+        // Cache the bits of `value`, if they are not already cached.
+        if let Some(bits_le) = candidate.bits_le.get() {
+            if value.bits_le.set(bits_le.clone()).is_ok() && candidate.are_bits_le_canonical.get().is_some() {
+                let _ = value.are_bits_le_canonical.set(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_enforce_bits_le(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element, and decompose a separate copy of it into bits.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let value = Field::<Circuit>::new(mode, expected);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+            // Compute the cost of reconstructing the bits.
+            let (num_private, num_constraints) = Circuit::scope(format!("{mode} {i} (from_bits_le)"), || {
+                let _candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+            });
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                Field::<Circuit>::enforce_bits_le(&value, &given_bits);
+                // Ensure the link costs a single constraint on top of the reconstruction, and no new variables.
+                match mode.is_constant() {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, num_private, num_constraints + 1),
+                }
+            });
+            assert!(Circuit::is_satisfied());
+
+            // Ensure the bits of the value are cached.
+            Circuit::scope(format!("{mode} {i} (to_bits_le)"), || {
+                assert_eq!(given_bits.eject_value(), value.to_bits_le().eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+
+            // Ensure mismatched bits are rejected.
+            if !mode.is_constant() {
+                let other: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let value = Field::<Circuit>::new(mode, expected);
+                let other_bits = Field::<Circuit>::new(mode, other).to_bits_le();
+                Field::<Circuit>::enforce_bits_le(&value, &other_bits);
+                assert_eq!(expected == other, Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_enforce_bits_le_constant() {
+        check_enforce_bits_le(Mode::Constant);
+    }
+
+    #[test]
+    fn test_enforce_bits_le_public() {
+        check_enforce_bits_le(Mode::Public);
+    }
+
+    #[test]
+    fn test_enforce_bits_le_private() {
+        check_enforce_bits_le(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_enforce_bits_le_constant_mismatch_fails() {
+        let value = Field::<Circuit>::constant(console::Field::from_u8(5));
+        let given_bits = Field::<Circuit>::constant(console::Field::from_u8(6)).to_bits_le();
+        Field::<Circuit>::enforce_bits_le(&value, &given_bits);
+    }
+}
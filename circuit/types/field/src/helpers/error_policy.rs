@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The policy for handling an unrecoverable error in a field gadget, such as corrupt internal state,
+/// which decides whether the gadget halts or returns an error.
+pub trait ErrorPolicy {
+    /// Handles the error with the given message, either by halting or by returning an error.
+    fn on_error<E: Environment>(message: &str) -> Result<()>;
+}
+
+/// The policy that halts on an error, with the given message (the default).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HaltPolicy;
+
+impl ErrorPolicy for HaltPolicy {
+    /// Halts with the given message.
+    fn on_error<E: Environment>(message: &str) -> Result<()> {
+        E::halt(message)
+    }
+}
+
+/// The policy that aborts the gadget on an error, by returning an error with the given message.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AbortPolicy;
+
+impl ErrorPolicy for AbortPolicy {
+    /// Returns an error with the given message.
+    fn on_error<E: Environment>(message: &str) -> Result<()> {
+        bail!("{message}")
+    }
+}
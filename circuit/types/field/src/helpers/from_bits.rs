@@ -23,39 +23,97 @@ impl<E: Environment> FromBits for Field<E> {
     ///   - If `bits_le` is longer than `E::BaseField::size_in_bits()`, the excess bits are enforced to be `0`s.
     ///   - If `bits_le` is shorter than `E::BaseField::size_in_bits()`, it is padded with `0`s up to base field size.
     fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Reconstruct the field element with the parameters of the base field.
+        let size_in_bits = E::BaseField::size_in_bits();
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        match Self::from_bits_le_with_params(bits_le, size_in_bits, size_in_data_bits, -E::BaseField::one()) {
+            Ok(output) => output,
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+
+    /// Initializes a new base field element from a list of big-endian bits *without* leading zeros.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Reverse the given bits from big-endian into little-endian.
+        // Note: This is safe as the bit representation is consistent (there are no leading zeros).
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, as in `from_bits_le`,
+    /// and returns the intermediate witnesses of the range check (see `RangeWitness`).
+    ///
+    /// If the bits fit within `E::BaseField::size_in_data_bits()`, the range check is skipped,
+    /// and the range witness is empty. This method incurs the same costs as `from_bits_le`.
+    pub fn from_bits_le_with_range_witness(bits_le: &[Boolean<E>]) -> (Self, RangeWitness<E>) {
+        match Self::from_bits_le_with_range_witness_and_policy::<HaltPolicy>(bits_le) {
+            Ok(output) => output,
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+
+    /// Initializes a new base field element from a list of **little-endian** bits, and returns the range witness,
+    /// where the error policy `P` decides how corrupt internal state is handled.
+    pub(crate) fn from_bits_le_with_range_witness_and_policy<P: ErrorPolicy>(
+        bits_le: &[Boolean<E>],
+    ) -> Result<(Self, RangeWitness<E>)> {
         // Retrieve the data and base field size.
         let size_in_data_bits = E::BaseField::size_in_data_bits();
         let size_in_bits = E::BaseField::size_in_bits();
 
+        // In debug builds, warn if the bits are likely given in big-endian order.
+        #[cfg(all(debug_assertions, feature = "debug_endianness"))]
+        if Self::is_likely_endianness_swap(&bits_le.iter().map(|bit| bit.eject_value()).collect::<Vec<_>>()) {
+            tracing::warn!(
+                "The bits given to `Field::from_bits_le` are likely in big-endian order, ensure this is intended"
+            );
+        }
+
+        // If every bit is constant `false` (e.g. padding), return zero, skipping the reconstruction.
+        if bits_le.iter().all(|bit| bit.is_constant() && !bit.eject_value()) {
+            let output = Field::zero();
+            output.cache_bits_le::<P>(vec![Boolean::constant(false); size_in_bits])?;
+            return Ok((output, RangeWitness::default()));
+        }
+
         // Ensure the list of booleans is within the allowed size in bits.
         let num_bits = bits_le.len();
         if num_bits > size_in_bits {
             // Check if all excess bits are zero.
             let should_be_zero = bits_le[size_in_bits..].iter().fold(Boolean::constant(false), |acc, bit| acc | bit);
             // Ensure `should_be_zero` is zero.
-            E::assert_eq(E::zero(), should_be_zero);
+            should_be_zero.enforce_false();
         }
 
-        // If `num_bits` is greater than `size_in_data_bits`, check it is less than `BaseField::MODULUS`.
-        if num_bits > size_in_data_bits {
-            // Retrieve the modulus & subtract by 1 as we'll check `bits_le` is less than or *equal* to this value.
-            // (For advanced users) BaseField::MODULUS - 1 is equivalent to -1 in the field.
-            let modulus_minus_one = -E::BaseField::one();
+        // Determine the number of bits up to `size_in_bits`, excluding any most significant bits
+        // that are constant `false`. As these bits do not contribute to the value, the range check
+        // below is skipped when the remaining bits fit within `size_in_data_bits`.
+        let num_nonzero_bits = bits_le[..num_bits.min(size_in_bits)]
+            .iter()
+            .rposition(|bit| !bit.is_constant() || bit.eject_value())
+            .map_or(0, |index| index + 1);
+
+        // If `num_nonzero_bits` is greater than `size_in_data_bits`, check it is less than `BaseField::MODULUS`.
+        let mut range_witness = RangeWitness::default();
+        if num_nonzero_bits > size_in_data_bits {
+            // If the bits are constant, halt with the offending value, as the range check would not be satisfiable.
+            if bits_le[..size_in_bits].iter().all(|bit| bit.is_constant()) {
+                Self::check_constant_bits_le_in_range(&bits_le[..size_in_bits]);
+            }
 
             // As `bits_le[size_in_bits..]` is guaranteed to be zero from the above logic,
             // and `bits_le` is greater than `size_in_data_bits`, it is safe to truncate `bits_le` to `size_in_bits`.
+            // Note: As `size_in_bits` is `size_in_data_bits + 1`, there is no length strictly between the two,
+            // and `num_nonzero_bits <= num_bits` ensures `bits_le` contains at least `size_in_bits` bits here.
             let bits_le = &bits_le[..size_in_bits];
 
-            // Compute `!((BaseField::MODULUS - 1) < bits_le)`, which is equivalent to `bits_le < BaseField::MODULUS`.
-            let is_less_than_modulus = !modulus_minus_one.to_bits_le().iter().zip_eq(bits_le).fold(
-                Boolean::constant(false),
-                |rest_is_less, (this, that)| {
-                    if *this { that.bitand(&rest_is_less) } else { that.bitor(&rest_is_less) }
-                },
-            );
-
             // Ensure the field element is less than `BaseField::MODULUS`.
-            E::assert(is_less_than_modulus);
+            range_witness = RangeWitness::new(bits_le);
+            range_witness.is_less_than_modulus().enforce_true();
         }
 
         // Reconstruct the bits as a linear combination representing the original field value.
@@ -63,48 +121,150 @@ impl<E: Environment> FromBits for Field<E> {
         let mut output = Field::zero();
         let mut coefficient = Field::one();
         for bit in bits_le.iter().take(size_in_bits) {
-            output += Field::from_boolean(bit) * &coefficient;
+            // Constant `false` bits do not contribute to the linear combination, and are skipped.
+            if !bit.is_constant() || bit.eject_value() {
+                output += Field::from_boolean(bit) * &coefficient;
+            }
             coefficient = coefficient.double();
         }
 
-        // Construct the sanitized list of bits, resizing up if necessary.
-        let mut bits_le = bits_le.iter().take(size_in_bits).cloned().collect::<Vec<_>>();
-        bits_le.resize(size_in_bits, Boolean::constant(false));
+        // Ensure the mode of the output matches the mode predicted from the bits.
+        debug_assert_eq!(
+            output.eject_mode(),
+            output_mode!(Field<E>, FromBits<Boolean = Boolean<E>>, &bits_le.iter().map(CircuitType::from).collect())
+        );
+
+        // Construct the sanitized list of bits. If the bits are exactly `size_in_bits` long (e.g. from `to_bits_le`),
+        // they are copied as is. Otherwise, they are truncated, or padded with constant `false`s, to `size_in_bits`.
+        let bits_le = match bits_le.len() == size_in_bits {
+            true => bits_le.to_vec(),
+            false => {
+                let mut bits_le = bits_le.iter().take(size_in_bits).cloned().collect::<Vec<_>>();
+                bits_le.resize(size_in_bits, Boolean::constant(false));
+                bits_le
+            }
+        };
 
         // Store the little-endian bits in the output.
-        if output.bits_le.set(bits_le).is_err() {
-            E::halt("Detected corrupt internal state for the bits of a field element")
+        output.cache_bits_le::<P>(bits_le)?;
+
+        Ok((output, range_witness))
+    }
+
+    /// Stores the given canonical **little-endian** bits as the cached bits of the field element,
+    /// where the error policy `P` decides how an already-populated cache is handled.
+    fn cache_bits_le<P: ErrorPolicy>(&self, bits_le: Vec<Boolean<E>>) -> Result<()> {
+        match self.bits_le.set(bits_le) {
+            Ok(()) => {
+                let _ = self.are_bits_le_canonical.set(());
+                Ok(())
+            }
+            Err(_) => P::on_error::<E>("Detected corrupt internal state for the bits of a field element"),
         }
+    }
 
-        output
+    /// Halts if the given constant **little-endian** bits, of at most `E::BaseField::size_in_bits()` bits,
+    /// represent a value that is greater than or equal to `BaseField::MODULUS`.
+    pub(crate) fn check_constant_bits_le_in_range(bits_le: &[Boolean<E>]) {
+        // Pack the bits into little-endian bytes.
+        let mut bytes = vec![0u8; (E::BaseField::size_in_bits() + 7) / 8];
+        for (i, _) in bits_le.iter().enumerate().filter(|(_, bit)| bit.eject_value()) {
+            bytes[i / 8] |= 1 << (i % 8);
+        }
+
+        // Ensure the value is unchanged when reduced modulo `BaseField::MODULUS`.
+        let value = E::BaseField::from_bytes_le_mod_order(&bytes);
+        if value.to_bits_le().iter().zip(bits_le).any(|(this, that)| *this != that.eject_value()) {
+            let hex = bytes.iter().rev().map(|byte| format!("{byte:02x}")).collect::<String>();
+            E::halt(format!("constant field bits exceed modulus: 0x{hex}"))
+        }
     }
 
-    /// Initializes a new base field element from a list of big-endian bits *without* leading zeros.
-    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
-        // Reverse the given bits from big-endian into little-endian.
-        // Note: This is safe as the bit representation is consistent (there are no leading zeros).
-        let mut bits_le = bits_be.to_vec();
-        bits_le.reverse();
+    /// Returns `true` if the given **little-endian** bits are likely big-endian bits, given in the wrong order.
+    ///
+    /// The heuristic flags a list of (at least) `E::BaseField::size_in_bits()` bits, where the lowest quarter
+    /// of the bits are all `0`s, while the highest quarter of the bits are not. This is typical of a small value
+    /// (e.g. a nonce or an index) whose big-endian bits are reversed, and occurs with negligible probability
+    /// for the bits of a uniformly random field element. The excess bits are not considered.
+    ///
+    /// With the `debug_endianness` feature, `from_bits_le` emits a `tracing` warning in debug builds when this returns `true`.
+    pub fn is_likely_endianness_swap(bits_le: &[bool]) -> bool {
+        // Retrieve the base field size.
+        let size_in_bits = E::BaseField::size_in_bits();
 
-        Self::from_bits_le(&bits_le)
+        // Only consider full-width lists of bits, as a shorter list of bits can not be a reversed field element.
+        if bits_le.len() < size_in_bits {
+            return false;
+        }
+        let bits_le = &bits_le[..size_in_bits];
+
+        // Check the lowest quarter of the bits are all `0`s, and the highest quarter of the bits are not.
+        let quarter = size_in_bits / 4;
+        let is_low_zero = bits_le[..quarter].iter().all(|bit| !bit);
+        let is_high_zero = bits_le[size_in_bits - quarter..].iter().all(|bit| !bit);
+        is_low_zero && !is_high_zero
+    }
+
+    /// Returns `true` if the given `E::BaseField::size_in_bits()` **little-endian** bits
+    /// represent a value that is less than `BaseField::MODULUS`.
+    pub(crate) fn is_less_than_modulus(bits_le: &[Boolean<E>]) -> Boolean<E> {
+        RangeWitness::new(bits_le).is_less_than_modulus()
     }
 }
 
 impl<E: Environment> Metrics<dyn FromBits<Boolean = Boolean<E>>> for Field<E> {
     type Case = Vec<Mode>;
 
-    fn count(_modes: &Self::Case) -> Count {
-        todo!()
+    fn count(modes: &Self::Case) -> Count {
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        // Count the number of non-constant bits within, and in excess of, the base field size.
+        let num_variable_bits = modes.iter().take(size_in_bits).filter(|mode| !mode.is_constant()).count() as u64;
+        let num_excess_bits = modes.iter().skip(size_in_bits).filter(|mode| !mode.is_constant()).count() as u64;
+
+        // The excess bits are folded with `num_excess_bits - 1` OR gates, and enforced to be zero.
+        let excess_count = match num_excess_bits {
+            0 => Count::zero(),
+            num_excess_bits => Count::is(0, 0, num_excess_bits - 1, num_excess_bits),
+        };
+
+        // The range check uses at most one gate per non-constant bit, and enforces the result.
+        // The reconstruction of the field element is a linear combination, and is free.
+        let range_check_count = match num_variable_bits {
+            0 => Count::zero(),
+            num_variable_bits => Count::less_than(0, 0, num_variable_bits, num_variable_bits + 1),
+        };
+
+        excess_count + range_check_count
     }
 }
 
 impl<E: Environment> OutputMode<dyn FromBits<Boolean = Boolean<E>>> for Field<E> {
-    type Case = Vec<Mode>;
-
+    type Case = Vec<CircuitType<Boolean<E>>>;
+
+    /// Returns the mode of the reconstructed field element, given the bits.
+    ///
+    /// As the field element is a linear combination of the bits (up to `E::BaseField::size_in_bits()`),
+    /// it is `Constant` if and only if each of these bits is constant. Otherwise, it is `Private`, except when
+    /// the linear combination is a lone public variable, i.e. the least significant bit is public
+    /// and every other bit is constant `false`, in which case it is `Public`.
+    ///
+    /// Note: A public bit in any other position is scaled by its power of two, and yields a `Private` field element.
     fn output_mode(case: &Self::Case) -> Mode {
-        match case.iter().all(|mode| mode.is_constant()) {
-            true => Mode::Constant,
-            false => Mode::Private,
+        // Retrieve the bits that are reconstructed, as the excess bits are only enforced to be zero.
+        let case = &case[..case.len().min(E::BaseField::size_in_bits())];
+
+        match case.split_first() {
+            // If every bit is constant, the field element is constant.
+            _ if case.iter().all(|bit| bit.mode().is_constant()) => Mode::Constant,
+            // If the least significant bit is public, and every other bit is constant `false`, it is public.
+            Some((CircuitType::Public, rest))
+                if rest.iter().all(|bit| matches!(bit, CircuitType::Constant(constant) if !constant.eject_value())) =>
+            {
+                Mode::Public
+            }
+            // Otherwise, the field element is private.
+            _ => Mode::Private,
         }
     }
 }
@@ -123,7 +283,8 @@ mod tests {
             // Sample a random element.
             let expected = Uniform::rand(&mut rng);
             let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
-            let expected_size_in_bits = given_bits.len();
+            // Note: The cached bits are resized to `size_in_bits`, independent of the number of given bits.
+            let expected_size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
 
             Circuit::scope(format!("{mode} {i}"), || {
                 let candidate = Field::<Circuit>::from_bits_le(&given_bits);
@@ -135,6 +296,15 @@ mod tests {
                 let candidate_bits = candidate.to_bits_le();
                 assert_eq!(expected_size_in_bits, candidate_bits.len());
                 assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                // Ensure a subsequent call to `to_bits_be` does not incur additional costs,
+                // as the big-endian bits are the reversed (cached) little-endian bits.
+                let candidate_bits_be = candidate.to_bits_be();
+                assert_eq!(expected_size_in_bits, candidate_bits_be.len());
+                for (bit_be, bit_le) in candidate_bits_be.iter().rev().zip_eq(&candidate_bits) {
+                    assert_eq!(bit_be.eject_value(), bit_le.eject_value());
+                }
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
             });
 
             // Add excess zero bits.
@@ -163,7 +333,8 @@ mod tests {
             // Sample a random element.
             let expected = Uniform::rand(&mut rng);
             let given_bits = Field::<Circuit>::new(mode, expected).to_bits_be();
-            let expected_size_in_bits = given_bits.len();
+            // Note: The cached bits are resized to `size_in_bits`, independent of the number of given bits.
+            let expected_size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
 
             Circuit::scope(format!("{mode} {i}"), || {
                 let candidate = Field::<Circuit>::from_bits_be(&given_bits);
@@ -175,6 +346,11 @@ mod tests {
                 let candidate_bits = candidate.to_bits_be();
                 assert_eq!(expected_size_in_bits, candidate_bits.len());
                 assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                // Ensure a subsequent call to `to_bits_le` does not incur additional costs.
+                let candidate_bits_le = candidate.to_bits_le();
+                assert_eq!(expected_size_in_bits, candidate_bits_le.len());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
             });
 
             // Add excess zero bits.
@@ -196,6 +372,372 @@ mod tests {
         }
     }
 
+    fn check_from_bits_le_partially_constant(mode: Mode) {
+        let mut rng = TestRng::default();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for num_variable_bits in [1, 8, 64, 128, 251, 252] {
+            // Sample a random element.
+            let value: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+            // Allocate the least significant bits in the given mode, and pad with constant `false` bits.
+            let given_bits = value
+                .to_bits_le()
+                .into_iter()
+                .enumerate()
+                .map(|(index, bit)| match index < num_variable_bits {
+                    true => Boolean::<Circuit>::new(mode, bit),
+                    false => Boolean::constant(false),
+                })
+                .take(size_in_bits)
+                .collect::<Vec<_>>();
+            let modes = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+
+            // Compute the expected value from the least significant bits alone.
+            let expected = Field::<Circuit>::from_bits_le(&given_bits[..num_variable_bits]).eject_value();
+
+            Circuit::scope(format!("{mode} {num_variable_bits}"), || {
+                let candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(size_in_bits, candidate.bits_le.get().expect("Caching failed").len());
+                // The range check is skipped, as the constant `false` bits keep the value below the modulus.
+                assert_scope!(0, 0, 0, 0);
+                assert_count!(FromBits<Boolean>() => Field, &modes);
+            });
+
+            // Add excess bits in the given mode.
+            let candidate = vec![given_bits, vec![Boolean::new(mode, false); num_variable_bits]].concat();
+            let modes = candidate.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+
+            Circuit::scope(format!("Excess {mode} {num_variable_bits}"), || {
+                let candidate = Field::<Circuit>::from_bits_le(&candidate);
+                assert_eq!(expected, candidate.eject_value());
+                match mode.is_constant() {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, num_variable_bits as u64 - 1, num_variable_bits as u64),
+                }
+                assert_count!(FromBits<Boolean>() => Field, &modes);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_partially_constant() {
+        check_from_bits_le_partially_constant(Mode::Constant);
+        check_from_bits_le_partially_constant(Mode::Public);
+        check_from_bits_le_partially_constant(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_bits_le_output_mode() {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        // Constructs the bits from the given bits, with the remaining bits padded as constant `false`.
+        let given_bits = |bits: Vec<Boolean<Circuit>>| {
+            let mut bits = bits;
+            bits.resize(size_in_bits, Boolean::constant(false));
+            bits
+        };
+
+        for (bits, expected_mode) in [
+            // All constant bits.
+            (given_bits(vec![Boolean::constant(true)]), Mode::Constant),
+            (given_bits(vec![]), Mode::Constant),
+            // One public bit among constant `false` bits.
+            (given_bits(vec![Boolean::new(Mode::Public, true)]), Mode::Public),
+            (given_bits(vec![Boolean::new(Mode::Public, false)]), Mode::Public),
+            (given_bits(vec![Boolean::constant(false), Boolean::new(Mode::Public, true)]), Mode::Private),
+            // One public bit among constant bits, with a constant `true` bit.
+            (given_bits(vec![Boolean::new(Mode::Public, true), Boolean::constant(true)]), Mode::Private),
+            // Multiple public bits.
+            (given_bits(vec![Boolean::new(Mode::Public, true), Boolean::new(Mode::Public, true)]), Mode::Private),
+            // One private bit among constant `false` bits.
+            (given_bits(vec![Boolean::new(Mode::Private, true)]), Mode::Private),
+            // A public bit and a private bit.
+            (given_bits(vec![Boolean::new(Mode::Public, true), Boolean::new(Mode::Private, true)]), Mode::Private),
+            // Constant bits, with a variable excess bit.
+            (
+                [given_bits(vec![Boolean::constant(true)]), vec![Boolean::new(Mode::Private, false)]].concat(),
+                Mode::Constant,
+            ),
+        ] {
+            let case = bits.iter().map(CircuitType::from).collect::<Vec<_>>();
+
+            let candidate = Field::<Circuit>::from_bits_le(&bits);
+            assert_eq!(expected_mode, candidate.eject_mode());
+            assert_output_mode!(FromBits<Boolean>() => Field, &case, candidate);
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits_le_arbitrary_bytes() {
+        let mut rng = TestRng::default();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let max_num_bytes = 2 * ((size_in_bits + 7) / 8);
+
+        for i in 0..ITERATIONS {
+            // Sample an arbitrary byte vector, of up to twice the size of a field element.
+            let num_bytes = u8::rand(&mut rng) as usize % (max_num_bytes + 1);
+            let bytes = (0..num_bytes).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+            let mut bits = bytes.iter().flat_map(|byte| (0..8).map(move |j| (byte >> j) & 1 == 1)).collect::<Vec<_>>();
+
+            // For half of the iterations, clear the most significant bits, to sample in-range vectors of every length.
+            if i % 2 == 0 {
+                let num_nonzero_bits = u16::rand(&mut rng) as usize % (bits.len() + 1);
+                bits[num_nonzero_bits..].fill(false);
+            }
+
+            // Compute the expected field element, which fails if the bits are out of range.
+            let expected = console::Field::<<Circuit as Environment>::Network>::from_bits_le(&bits);
+
+            for mode in [Mode::Constant, Mode::Private] {
+                let given_bits = bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+                match &expected {
+                    // Ensure in-range bits reconstruct the expected value, and round-trip within `size_in_bits`.
+                    Ok(expected) => {
+                        let candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                        assert_eq!(*expected, candidate.eject_value());
+
+                        let candidate_bits = candidate.to_bits_le();
+                        assert_eq!(size_in_bits, candidate_bits.len());
+                        for (index, candidate_bit) in candidate_bits.iter().enumerate() {
+                            assert_eq!(bits.get(index).copied().unwrap_or(false), candidate_bit.eject_value());
+                        }
+                        assert!(Circuit::is_satisfied());
+                    }
+                    // Ensure constant out-of-range bits halt, as the range check is evaluated directly.
+                    Err(_) if mode.is_constant() => {
+                        let result = std::panic::catch_unwind(|| Field::<Circuit>::from_bits_le(&given_bits));
+                        let error = result.expect_err("Constant out-of-range bits must halt");
+                        let message = error.downcast_ref::<String>().expect("The halt message must be a string");
+                        assert!(message.contains("constant field bits exceed modulus"), "Unexpected halt: {message}");
+                    }
+                    // Ensure out-of-range bits leave the circuit unsatisfied.
+                    Err(_) => {
+                        let _candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                        assert!(!Circuit::is_satisfied());
+                    }
+                }
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_cached_length() {
+        let mut rng = TestRng::default();
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bits in [0, 1, 64, size_in_data_bits, size_in_bits, size_in_bits + 3] {
+                // Sample a random element, and truncate (or extend) its bits to `num_bits`.
+                let value: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let mut bits = value.to_bits_le();
+                bits.truncate(num_bits.min(size_in_data_bits));
+                bits.resize(num_bits, false);
+                let given_bits = bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+                // Ensure the cached bits are exactly `size_in_bits` long, and match the given bits.
+                let candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                let candidate_bits = candidate.bits_le.get().expect("Caching failed");
+                assert_eq!(size_in_bits, candidate_bits.len());
+                for (index, candidate_bit) in candidate_bits.iter().enumerate() {
+                    assert_eq!(bits.get(index).copied().unwrap_or(false), candidate_bit.eject_value());
+                }
+                assert_eq!(size_in_bits, candidate.to_bits_le().len());
+            }
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits_le_full_width_cache() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                // Sample a random element, and take its full-width bits.
+                let given_bits = Field::<Circuit>::new(mode, Uniform::rand(&mut rng)).to_bits_le();
+                assert_eq!(<Circuit as Environment>::BaseField::size_in_bits(), given_bits.len());
+
+                // Ensure the cached bits are the given bits, without any padding.
+                let candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                let candidate_bits = candidate.bits_le.get().expect("Caching failed");
+                assert_eq!(given_bits.len(), candidate_bits.len());
+                for (given_bit, candidate_bit) in given_bits.iter().zip_eq(candidate_bits) {
+                    assert_eq!(format!("{:?}", **given_bit), format!("{:?}", **candidate_bit));
+                }
+
+                // Ensure the element matches the one reconstructed from the bits with an excess zero bit,
+                // which takes the truncating path.
+                let mut padded_bits = given_bits.clone();
+                padded_bits.push(Boolean::constant(false));
+                let expected = Field::<Circuit>::from_bits_le(&padded_bits);
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+                assert_eq!(expected.bits_le.get().unwrap().eject_value(), candidate_bits.eject_value());
+            }
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits_le_count() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random element.
+            let given_bits = Field::<Circuit>::new(mode, Uniform::rand(&mut rng)).to_bits_le();
+            let modes = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode}"), || {
+                let _candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                assert_count!(FromBits<Boolean>() => Field, &modes);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_bits_le_boundary_lengths(mode: Mode) {
+        let mut rng = TestRng::default();
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for num_bits in (size_in_data_bits - 1)..=(size_in_bits + 1) {
+            for i in 0..ITERATIONS {
+                // Sample a random element, and truncate or pad its bits to `num_bits`.
+                let value: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let mut bits = value.to_bits_le();
+                bits.resize(num_bits, false);
+                let expected = console::Field::<<Circuit as Environment>::Network>::from_bits_le(&bits).unwrap();
+
+                let given_bits = bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+                Circuit::scope(format!("{mode} {num_bits} {i}"), || {
+                    let candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(size_in_bits, candidate.bits_le.get().expect("Caching failed").len());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_boundary_lengths() {
+        check_from_bits_le_boundary_lengths(Mode::Constant);
+        check_from_bits_le_boundary_lengths(Mode::Public);
+        check_from_bits_le_boundary_lengths(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_bits_le_constant_zero() {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for num_bits in [0, 1, size_in_bits - 1, size_in_bits, size_in_bits + 1, 2 * size_in_bits] {
+            let given_bits = vec![Boolean::<Circuit>::constant(false); num_bits];
+
+            Circuit::scope(format!("Zero {num_bits}"), || {
+                let candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                assert!(candidate.is_constant());
+                assert_eq!(Field::<Circuit>::zero().eject_value(), candidate.eject_value());
+                // Ensure the cached bits are resized to `size_in_bits`.
+                assert_eq!(size_in_bits, candidate.bits_le.get().unwrap().len());
+                assert!(candidate.to_bits_le().iter().all(|bit| bit.is_constant() && !bit.eject_value()));
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_exhaustive_windows() {
+        const WINDOW_SIZE: usize = 7;
+
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        // Retrieve the bits of `BaseField::MODULUS - 1`.
+        let mut modulus_minus_one = (-<Circuit as Environment>::BaseField::one()).to_bits_le();
+        modulus_minus_one.truncate(size_in_bits);
+
+        // Enumerate every value of a window of bits, at the least and most significant bits and in between,
+        // where the remaining bits are those of `BaseField::MODULUS - 1`, all `false`, or all `true`.
+        for start in [0, size_in_bits / 2, size_in_bits - WINDOW_SIZE] {
+            for remaining in [None, Some(false), Some(true)] {
+                for window in 0..(1u32 << WINDOW_SIZE) {
+                    let bits = (0..size_in_bits)
+                        .map(|i| match (start..start + WINDOW_SIZE).contains(&i) {
+                            true => (window >> (i - start)) & 1 == 1,
+                            false => remaining.unwrap_or(modulus_minus_one[i]),
+                        })
+                        .collect::<Vec<_>>();
+                    // The bits are in range if and only if they are at most `BaseField::MODULUS - 1`,
+                    // comparing from the most significant bit.
+                    let is_in_range =
+                        bits.iter().rev().cmp(modulus_minus_one.iter().rev()) != std::cmp::Ordering::Greater;
+
+                    Circuit::scope(format!("{start} {remaining:?} {window}"), || {
+                        let given_bits = bits.iter().map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit));
+                        let candidate = Field::<Circuit>::from_bits_le(&given_bits.collect::<Vec<_>>());
+                        // Ensure the range check is satisfied if and only if the bits are in range.
+                        assert_eq!(is_in_range, Circuit::is_satisfied_in_scope());
+                        if is_in_range {
+                            let positions = (0..size_in_bits).filter(|i| bits[*i]).collect::<Vec<_>>();
+                            let expected = Field::<Circuit>::from_set_positions_le(&positions);
+                            assert_eq!(expected.eject_value(), candidate.eject_value());
+                        }
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cache_bits_le_abort_policy() {
+        let mut rng = TestRng::default();
+
+        // Populate the cache of a field element.
+        let candidate = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+        let expected = candidate.to_bits_le();
+
+        // Ensure storing conflicting bits returns an error, and leaves the cache unchanged.
+        let result = candidate.cache_bits_le::<AbortPolicy>(vec![Boolean::constant(true); expected.len()]);
+        assert!(result.unwrap_err().to_string().contains("Detected corrupt internal state"));
+        assert_eq!(expected.eject_value(), candidate.bits_le.get().unwrap().eject_value());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic(expected = "Detected corrupt internal state")]
+    fn test_cache_bits_le_halt_policy_fails() {
+        let mut rng = TestRng::default();
+
+        // Populate the cache of a field element.
+        let candidate = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+        let expected = candidate.to_bits_le();
+
+        // Ensure storing conflicting bits halts.
+        let _ = candidate.cache_bits_le::<HaltPolicy>(vec![Boolean::constant(true); expected.len()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "constant field bits exceed modulus: 0x")]
+    fn test_from_bits_le_constant_out_of_range_fails() {
+        // Ensure a constant `BaseField::MODULUS` halts with a descriptive message.
+        let mut given_bits = (-<Circuit as Environment>::BaseField::one()).to_bits_le();
+        // Set the least significant bit, as `MODULUS - 1` is even.
+        given_bits[0] = true;
+        let given_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(Mode::Constant, *bit)).collect::<Vec<_>>();
+        let _candidate = Field::<Circuit>::from_bits_le(&given_bits);
+    }
+
     #[test]
     fn test_from_bits_le_constant() {
         check_from_bits_le(Mode::Constant, 0, 0, 0, 0);
@@ -225,4 +767,106 @@ mod tests {
     fn test_from_bits_be_private() {
         check_from_bits_be(Mode::Private, 0, 0, 252, 253);
     }
+
+    #[test]
+    fn test_is_likely_endianness_swap() {
+        let mut rng = TestRng::default();
+
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for i in 0..ITERATIONS {
+            // Ensure the bits of a random element are not flagged.
+            let element: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            assert!(!Field::<Circuit>::is_likely_endianness_swap(&element.to_bits_le()));
+
+            // Ensure the bits of a small value are not flagged.
+            let value = console::Field::<<Circuit as Environment>::Network>::from_u64(u64::rand(&mut rng) | (1 << 63));
+            let bits_le = value.to_bits_le();
+            assert!(!Field::<Circuit>::is_likely_endianness_swap(&bits_le));
+
+            // Ensure the reversed bits of a small value are flagged.
+            let mut bits_be = bits_le.clone();
+            bits_be.reverse();
+            assert!(Field::<Circuit>::is_likely_endianness_swap(&bits_be));
+
+            // Ensure `from_bits_le` is unchanged by the diagnostic.
+            let candidate_bits =
+                bits_be.iter().map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit)).collect::<Vec<_>>();
+            let candidate = Field::<Circuit>::from_bits_le(&candidate_bits);
+            match console::Field::<<Circuit as Environment>::Network>::from_bits_le(&bits_be) {
+                Ok(expected) => {
+                    assert_eq!(expected, candidate.eject_value());
+                    assert!(Circuit::is_satisfied());
+                }
+                Err(_) => assert!(!Circuit::is_satisfied()),
+            }
+            Circuit::reset();
+
+            // Ensure lists of bits that are shorter than the field size are not flagged.
+            assert!(!Field::<Circuit>::is_likely_endianness_swap(&bits_be[size_in_bits - i as usize % 64 - 1..]));
+        }
+    }
+
+    #[cfg(all(debug_assertions, feature = "debug_endianness"))]
+    #[test]
+    fn test_from_bits_le_warns_on_endianness_swap() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        /// A subscriber that counts the warnings emitted by this crate.
+        struct WarningCounter(Arc<AtomicUsize>);
+
+        impl tracing::Subscriber for WarningCounter {
+            fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+                tracing::span::Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+            fn event(&self, event: &tracing::Event<'_>) {
+                let metadata = event.metadata();
+                if *metadata.level() == tracing::Level::WARN
+                    && metadata.target().starts_with("snarkvm_circuit_types_field")
+                {
+                    self.0.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+
+            fn enter(&self, _span: &tracing::span::Id) {}
+
+            fn exit(&self, _span: &tracing::span::Id) {}
+        }
+
+        let mut rng = TestRng::default();
+
+        // Sample the bits of a small value.
+        let value = console::Field::<<Circuit as Environment>::Network>::from_u64(u64::rand(&mut rng) | (1 << 63));
+        let bits_le = value.to_bits_le();
+        let mut bits_be = bits_le.clone();
+        bits_be.reverse();
+
+        let num_warnings = Arc::new(AtomicUsize::new(0));
+        tracing::subscriber::with_default(WarningCounter(num_warnings.clone()), || {
+            // Ensure the little-endian bits do not emit a warning.
+            let candidate_bits =
+                bits_le.iter().map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit)).collect::<Vec<_>>();
+            let _candidate = Field::<Circuit>::from_bits_le(&candidate_bits);
+            assert_eq!(0, num_warnings.load(Ordering::SeqCst));
+
+            // Ensure the reversed bits emit a warning.
+            let candidate_bits =
+                bits_be.iter().map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit)).collect::<Vec<_>>();
+            let _candidate = Field::<Circuit>::from_bits_le(&candidate_bits);
+            assert_eq!(1, num_warnings.load(Ordering::SeqCst));
+        });
+        Circuit::reset();
+    }
 }
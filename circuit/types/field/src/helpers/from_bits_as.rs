@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, in the given `mode`.
+    ///
+    /// If the reconstructed field element is not already in the given `mode`, a new variable is allocated
+    /// in the given `mode`, and enforced to be equal to the reconstructed field element.
+    /// This incurs one additional variable and one additional constraint, on top of `from_bits_le`.
+    ///
+    /// Note: A non-constant field element can not be reconstructed as a constant, and will halt.
+    #[cfg(console)]
+    pub fn from_bits_le_as(bits_le: &[Boolean<E>], mode: Mode) -> Self {
+        // Reconstruct the field element.
+        let output = Self::from_bits_le(bits_le);
+
+        match (output.eject_mode(), mode) {
+            // If the field element is already in the given mode, return it.
+            (output_mode, mode) if output_mode == mode => output,
+            // Ensure a non-constant field element is not reconstructed as a constant.
+            (_, Mode::Constant) => E::halt("Cannot reconstruct a non-constant field element as a constant"),
+            // Otherwise, allocate a new variable in the given mode.
+            (_, mode) => {
+                let candidate = Self::new(mode, output.eject_value());
+                // Ensure the new variable is equal to the reconstructed field element.
+                E::assert_eq(&candidate, &output);
+
+                // Store the little-endian bits in the new variable.
+                if let Some(bits_le) = output.bits_le.get() {
+                    if candidate.bits_le.set(bits_le.clone()).is_err() {
+                        E::halt("Detected corrupt internal state for the bits of a field element")
+                    }
+                    if output.are_bits_le_canonical.get().is_some() {
+                        let _ = candidate.are_bits_le_canonical.set(());
+                    }
+                }
+                candidate
+            }
+        }
+    }
+}
+
+/// The operation of `Field::from_bits_le_as`, which keys its `Metrics` and `OutputMode`.
+pub trait FromBitsAs {}
+
+impl<E: Environment> Metrics<dyn FromBitsAs> for Field<E> {
+    /// The bits, and the requested mode.
+    type Case = (Vec<CircuitType<Boolean<E>>>, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (bits, mode) = case;
+
+        // Reconstruct the field element.
+        let modes = bits.iter().map(|bit| bit.mode()).collect::<Vec<_>>();
+        let from_bits_count = count!(Field<E>, FromBits<Boolean = Boolean<E>>, &modes);
+
+        // If the reconstructed field element is not in the requested mode, allocate a new variable
+        // in the requested mode, and enforce it is equal to the reconstructed field element.
+        // Note: A non-constant field element can not be reconstructed as a constant, and halts.
+        match (output_mode!(Field<E>, FromBits<Boolean = Boolean<E>>, bits), mode) {
+            (output_mode, mode) if output_mode == *mode => from_bits_count,
+            (_, Mode::Constant) => from_bits_count,
+            (_, Mode::Public) => from_bits_count + Count::is(0, 1, 0, 1),
+            (_, Mode::Private) => from_bits_count + Count::is(0, 0, 1, 1),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn FromBitsAs> for Field<E> {
+    /// The bits, and the requested mode.
+    type Case = (Vec<CircuitType<Boolean<E>>>, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        case.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_as(
+        mode: Mode,
+        target_mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+            Circuit::scope(format!("{mode} {target_mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_as(&given_bits, target_mode);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(target_mode, candidate.eject_mode());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                let case = (given_bits.iter().map(CircuitType::from).collect::<Vec<_>>(), target_mode);
+                assert_count!(Field<Circuit>, FromBitsAs, &case);
+                assert_output_mode!(Field<Circuit>, FromBitsAs, &case, candidate);
+
+                // Ensure a subsequent call to `to_bits_le` does not incur additional costs.
+                let candidate_bits = candidate.to_bits_le();
+                assert_eq!(given_bits.len(), candidate_bits.len());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_as() {
+        check_from_bits_le_as(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
+        check_from_bits_le_as(Mode::Constant, Mode::Public, 0, 1, 0, 1);
+        check_from_bits_le_as(Mode::Constant, Mode::Private, 0, 0, 1, 1);
+        check_from_bits_le_as(Mode::Public, Mode::Public, 0, 1, 252, 254);
+        check_from_bits_le_as(Mode::Public, Mode::Private, 0, 0, 252, 253);
+        check_from_bits_le_as(Mode::Private, Mode::Public, 0, 1, 252, 254);
+        check_from_bits_le_as(Mode::Private, Mode::Private, 0, 0, 252, 253);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bits_le_as_constant_fails() {
+        let given_bits = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut TestRng::default())).to_bits_le();
+        let _candidate = Field::<Circuit>::from_bits_le_as(&given_bits, Mode::Constant);
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits,
+    /// *assuming* the excess bits (beyond `E::BaseField::size_in_bits()`) are `0`s.
+    ///
+    /// The caller must guarantee the excess bits are zero (e.g. by an upstream constraint), as they are ignored.
+    /// This saves the `num_excess_bits - 1` private variables and `num_excess_bits` constraints
+    /// that `from_bits_le` uses to enforce the (non-constant) excess bits are zero.
+    pub fn from_bits_le_assume_excess_zero(bits_le: &[Boolean<E>]) -> Self {
+        // Truncate the bits to `size_in_bits`, discarding the excess bits.
+        Self::from_bits_le(&bits_le[..bits_le.len().min(E::BaseField::size_in_bits())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_assume_excess_zero(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element, with `i` excess zero bits.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = [expected.to_bits_le(), vec![false; i as usize]].concat();
+            let given_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                // Reconstruct the field element, enforcing the excess bits are zero.
+                let (num_private_before, num_constraints_before) = (Circuit::num_private(), Circuit::num_constraints());
+                let candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                let num_private = Circuit::num_private() - num_private_before;
+                let num_constraints = Circuit::num_constraints() - num_constraints_before;
+
+                // Reconstruct the field element, assuming the excess bits are zero.
+                let (num_private_before, num_constraints_before) = (Circuit::num_private(), Circuit::num_constraints());
+                let candidate = Field::<Circuit>::from_bits_le_assume_excess_zero(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                let num_assumed_private = Circuit::num_private() - num_private_before;
+                let num_assumed_constraints = Circuit::num_constraints() - num_constraints_before;
+
+                // Ensure the savings are exactly the cost of enforcing the excess bits are zero.
+                match (mode.is_constant(), i) {
+                    (true, _) | (false, 0) => {
+                        assert_eq!((num_private, num_constraints), (num_assumed_private, num_assumed_constraints))
+                    }
+                    (false, i) => {
+                        assert_eq!(num_private - num_assumed_private, i - 1);
+                        assert_eq!(num_constraints - num_assumed_constraints, i);
+                    }
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_assume_excess_zero() {
+        check_from_bits_le_assume_excess_zero(Mode::Constant);
+        check_from_bits_le_assume_excess_zero(Mode::Public);
+        check_from_bits_le_assume_excess_zero(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_bits_le_assume_excess_zero_ignores_excess_bits() {
+        // Ensure nonzero excess bits are ignored, rather than enforced to be zero.
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let mut given_bits = vec![Boolean::<Circuit>::new(Mode::Private, false); size_in_bits];
+        given_bits.push(Boolean::new(Mode::Private, true));
+
+        let candidate = Field::<Circuit>::from_bits_le_assume_excess_zero(&given_bits);
+        assert!(candidate.eject_value().is_zero());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, reduced modulo
+    /// `BaseField::MODULUS`, and returns a boolean that is `true` if and only if the integer value
+    /// of the bits is greater than or equal to `BaseField::MODULUS`.
+    ///
+    /// Unlike `from_bits_le`, the range check (including on the excess bits) is not enforced,
+    /// and its result is returned instead, so that the caller can detect whether a reduction occurred.
+    pub fn from_bits_le_checked_reduction(bits_le: &[Boolean<E>]) -> (Self, Boolean<E>) {
+        // Retrieve the data and base field size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        // If the bits fit within `size_in_data_bits`, the value is guaranteed to be less than `BaseField::MODULUS`.
+        if bits_le.len() <= size_in_data_bits {
+            return (Self::from_bits_le(bits_le), Boolean::constant(false));
+        }
+
+        // Check if any of the excess bits are set.
+        let is_excess_nonzero = bits_le[size_in_bits..].iter().fold(Boolean::constant(false), |acc, bit| acc | bit);
+
+        // Determine if the value is greater than or equal to `BaseField::MODULUS`.
+        // Note: As `size_in_bits` is `size_in_data_bits + 1`, `bits_le` contains at least `size_in_bits` bits here.
+        let is_reduced = !Self::is_less_than_modulus(&bits_le[..size_in_bits]) | is_excess_nonzero;
+
+        // Reconstruct all of the bits as a linear combination, which reduces the value modulo `BaseField::MODULUS`.
+        // `output` := (2^i * b_i + ... + 2^0 * b_0)
+        let mut output = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in bits_le {
+            output += Field::from_boolean(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+
+        (output, is_reduced)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_checked_reduction(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Returns the given bits reduced modulo `BaseField::MODULUS`, computed natively.
+        let reduce = |bits_le: &[bool]| {
+            let mut output = console::Field::<<Circuit as Environment>::Network>::zero();
+            let mut coefficient = console::Field::one();
+            for bit in bits_le {
+                if *bit {
+                    output += coefficient;
+                }
+                coefficient = coefficient.double();
+            }
+            output
+        };
+
+        // Ensure in-range bits are not reduced.
+        for i in 0..ITERATIONS {
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let (candidate, is_reduced) = Field::<Circuit>::from_bits_le_checked_reduction(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(!is_reduced.eject_value());
+            });
+            Circuit::reset();
+        }
+
+        // Construct the bits of `BaseField::MODULUS`, by setting the least significant bit of `BaseField::MODULUS - 1`.
+        let modulus_minus_one_bits_le = (-<Circuit as Environment>::BaseField::one()).to_bits_le();
+        let mut modulus_bits_le = modulus_minus_one_bits_le.clone();
+        modulus_bits_le[0] = true;
+        let size_in_bits = modulus_bits_le.len();
+
+        for (bits_le, expected_is_reduced) in [
+            (modulus_minus_one_bits_le.clone(), false),
+            ([modulus_minus_one_bits_le, vec![false; 3]].concat(), false),
+            (vec![true; 8], false),
+            (modulus_bits_le.clone(), true),
+            (vec![true; size_in_bits], true),
+            ([vec![false; size_in_bits], vec![true]].concat(), true),
+            ([modulus_bits_le, vec![false]].concat(), true),
+        ] {
+            let given_bits = bits_le.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+            let (candidate, is_reduced) = Field::<Circuit>::from_bits_le_checked_reduction(&given_bits);
+            assert_eq!(reduce(&bits_le), candidate.eject_value());
+            assert_eq!(expected_is_reduced, is_reduced.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_checked_reduction() {
+        check_from_bits_le_checked_reduction(Mode::Constant);
+        check_from_bits_le_checked_reduction(Mode::Public);
+        check_from_bits_le_checked_reduction(Mode::Private);
+    }
+}
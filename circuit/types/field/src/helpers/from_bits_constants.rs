@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Returns the native base field elements that `from_bits_le` reconstructs from each of the given lists
+    /// of constant **little-endian** bits, without allocating any circuit elements.
+    ///
+    /// Rather than accumulating each bit with a doubling, each list of bits is packed into bytes,
+    /// and converted with a single modular reduction, which is checked to leave the value unchanged.
+    ///
+    /// Note: A list of bits with nonzero excess bits, or that is not less than `BaseField::MODULUS`, will halt.
+    pub fn from_bits_le_constants(batches: &[Vec<bool>]) -> Vec<E::BaseField> {
+        // Retrieve the base field size.
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        batches
+            .iter()
+            .map(|bits_le| {
+                // Ensure the excess bits are zero.
+                if bits_le.iter().skip(size_in_bits).any(|bit| *bit) {
+                    E::halt("Detected nonzero excess bits while reconstructing a constant field element")
+                }
+                let bits_le = &bits_le[..bits_le.len().min(size_in_bits)];
+
+                // Pack the bits into little-endian bytes.
+                let mut bytes = vec![0u8; (size_in_bits + 7) / 8];
+                for (i, _) in bits_le.iter().enumerate().filter(|(_, bit)| **bit) {
+                    bytes[i / 8] |= 1 << (i % 8);
+                }
+
+                // Convert the bytes into a field element, and ensure the value is less than `BaseField::MODULUS`.
+                // Note: As the bits are at most `size_in_bits` bits, the value is unchanged if and only if
+                // the bits of the field element match the given bits.
+                let output = E::BaseField::from_bytes_le_mod_order(&bytes);
+                if output.to_bits_le().iter().zip(bits_le).any(|(this, that)| this != that) {
+                    E::halt("Detected a constant field element that is not less than the modulus")
+                }
+                output
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits_le_constants() {
+        let mut rng = TestRng::default();
+
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        // Sample lists of bits of random elements, with and without excess zero bits, and of varying lengths.
+        let mut batches =
+            vec![vec![], vec![true], (-console::Field::<<Circuit as Environment>::Network>::one()).to_bits_le()];
+        for i in 0..ITERATIONS {
+            let element: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let mut bits_le = element.to_bits_le();
+            bits_le.resize(size_in_bits + (i as usize % 4), false);
+            batches.push(bits_le);
+            batches.push((0..i as usize % size_in_bits).map(|_| Uniform::rand(&mut rng)).collect());
+        }
+
+        // Ensure the batch results match reconstructing each list of constant bits.
+        let candidates = Field::<Circuit>::from_bits_le_constants(&batches);
+        assert_eq!(batches.len(), candidates.len());
+        for (bits_le, candidate) in batches.iter().zip_eq(candidates) {
+            let bits_le = bits_le.iter().map(|bit| Boolean::<Circuit>::constant(*bit)).collect::<Vec<_>>();
+            assert_eq!(*Field::<Circuit>::from_bits_le(&bits_le).eject_value(), candidate);
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bits_le_constants_out_of_range() {
+        // Ensure a list of bits that is not less than `BaseField::MODULUS` is rejected.
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let _ = Field::<Circuit>::from_bits_le_constants(&[vec![true; size_in_bits]]);
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, as in `from_bits_le`,
+    /// where the witness assignment does not branch on, or exit early because of, the values of the bits.
+    ///
+    /// Unlike `from_bits_le`, every bit is visited: constant `false` bits are not skipped, the excess bits
+    /// are folded in full, and the range check is performed whenever there are more than
+    /// `E::BaseField::size_in_data_bits()` bits. The control flow depends only on the number of bits,
+    /// whether they are all constant, and the bits of `BaseField::MODULUS - 1`, which are all public.
+    ///
+    /// Note: This is constant-time at the assignment level of this method; the gadgets it invokes
+    /// (e.g. `Boolean::bitand`) compute their witnesses with native boolean operations.
+    pub fn from_bits_le_ct(bits_le: &[Boolean<E>]) -> Self {
+        // Retrieve the data and base field size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        // Ensure all excess bits are zero, visiting every excess bit.
+        let (bits_le, excess_bits) = bits_le.split_at(bits_le.len().min(size_in_bits));
+        if !excess_bits.is_empty() {
+            excess_bits.iter().fold(Boolean::constant(false), |acc, bit| acc | bit).enforce_false();
+        }
+
+        // If there are more than `size_in_data_bits` bits, check they are less than `BaseField::MODULUS`.
+        if bits_le.len() > size_in_data_bits {
+            // If the bits are constant, halt with the offending value, as the range check would not be satisfiable.
+            if bits_le.iter().all(|bit| bit.is_constant()) {
+                Self::check_constant_bits_le_in_range(bits_le);
+            }
+            RangeWitness::new(bits_le).is_less_than_modulus().enforce_true();
+        }
+
+        // Reconstruct the bits as a linear combination, including every bit.
+        // `output` := (2^i * b_i + ... + 2^0 * b_0)
+        let mut output = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in bits_le {
+            output += Field::from_boolean(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+
+        // Store the little-endian bits in the output, resizing up if necessary.
+        let mut bits_le = bits_le.to_vec();
+        bits_le.resize(size_in_bits, Boolean::constant(false));
+        if output.bits_le.set(bits_le).is_err() {
+            E::halt("Detected corrupt internal state for the bits of a field element")
+        }
+        let _ = output.are_bits_le_canonical.set(());
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_ct(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for num_bits in [0, 1, 64, size_in_data_bits, size_in_bits, size_in_bits + 10] {
+            for i in 0..ITERATIONS {
+                // Sample a random element, truncated to `num_bits` bits, with zero excess bits.
+                let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let mut given_bits = expected.to_bits_le();
+                given_bits.resize(num_bits, false);
+                let expected = console::Field::from_bits_le(&given_bits).unwrap();
+                let given_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+                Circuit::scope(format!("{mode} {num_bits} {i}"), || {
+                    let candidate = Field::<Circuit>::from_bits_le_ct(&given_bits);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(Field::<Circuit>::from_bits_le(&given_bits).eject_value(), candidate.eject_value());
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+
+        // Ensure `BaseField::MODULUS` is rejected.
+        if !mode.is_constant() {
+            let mut modulus_bits_le = (-<Circuit as Environment>::BaseField::one()).to_bits_le();
+            modulus_bits_le[0] = true;
+            let given_bits = modulus_bits_le.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+            let _candidate = Field::<Circuit>::from_bits_le_ct(&given_bits);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_ct() {
+        check_from_bits_le_ct(Mode::Constant);
+        check_from_bits_le_ct(Mode::Public);
+        check_from_bits_le_ct(Mode::Private);
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of bits in the given `endianness`.
+    ///
+    /// This method dispatches to `from_bits_le` or `from_bits_be`, and incurs the same costs.
+    pub fn from_bits(bits: &[Boolean<E>], endianness: Endianness) -> Self {
+        match endianness {
+            Endianness::Little => Self::from_bits_le(bits),
+            Endianness::Big => Self::from_bits_be(bits),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(&mut rng);
+            let bits_le = Field::<Circuit>::new(mode, expected).to_bits_le();
+            let bits_be = Field::<Circuit>::new(mode, expected).to_bits_be();
+
+            // Add excess zero bits.
+            let excess = vec![Boolean::new(mode, false); i as usize];
+            let bits_le = vec![bits_le, excess.clone()].concat();
+            let bits_be = vec![excess, bits_be].concat();
+
+            // Ensure `from_bits` matches `from_bits_le`.
+            let candidate = Field::<Circuit>::from_bits(&bits_le, Endianness::Little);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(Field::<Circuit>::from_bits_le(&bits_le).eject_value(), candidate.eject_value());
+
+            // Ensure `from_bits` matches `from_bits_be`.
+            let candidate = Field::<Circuit>::from_bits(&bits_be, Endianness::Big);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(Field::<Circuit>::from_bits_be(&bits_be).eject_value(), candidate.eject_value());
+
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_bits_out_of_range(endianness: Endianness) {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        // Ensure a nonzero excess bit is rejected.
+        let mut bits = vec![Boolean::<Circuit>::new(Mode::Private, false); size_in_bits + 1];
+        let excess_index = match endianness {
+            Endianness::Little => size_in_bits,
+            Endianness::Big => 0,
+        };
+        bits[excess_index] = Boolean::new(Mode::Private, true);
+        let _candidate = Field::<Circuit>::from_bits(&bits, endianness);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure a value greater than or equal to the modulus is rejected.
+        let mut bits = (-<Circuit as Environment>::BaseField::one())
+            .to_bits_le()
+            .iter()
+            .map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit))
+            .collect::<Vec<_>>();
+        // Set the least significant bit, as `MODULUS - 1` is even.
+        bits[0] = Boolean::new(Mode::Private, true);
+        if endianness == Endianness::Big {
+            bits.reverse();
+        }
+        let _candidate = Field::<Circuit>::from_bits(&bits, endianness);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn check_from_bits_over_length(endianness: Endianness) {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let minus_one = -<Circuit as Environment>::BaseField::one();
+
+        // Returns the given little-endian bits, padded with the given excess bits, in the given endianness.
+        let to_bits = |bits_le: &[bool], excess_bits: &[bool]| {
+            let mut bits = [bits_le, excess_bits].concat();
+            if endianness == Endianness::Big {
+                bits.reverse();
+            }
+            bits.iter().map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit)).collect::<Vec<_>>()
+        };
+
+        // Compute the bits of `MODULUS - 1`, and of `MODULUS`, by setting the least significant bit of `MODULUS - 1`.
+        let mut minus_one_bits = minus_one.to_bits_le();
+        minus_one_bits.truncate(size_in_bits);
+        let mut modulus_bits = minus_one_bits.clone();
+        modulus_bits[0] = true;
+
+        for num_excess_bits in [1, 8, 64, size_in_bits] {
+            let zero_bits = vec![false; num_excess_bits];
+            let mut nonzero_bits = zero_bits.clone();
+            nonzero_bits[num_excess_bits - 1] = true;
+
+            // Ensure the largest field element, with excess zero bits, is reconstructed.
+            let candidate = Field::<Circuit>::from_bits(&to_bits(&minus_one_bits, &zero_bits), endianness);
+            assert_eq!(minus_one, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure the modulus, with excess zero bits, is rejected by the range check.
+            let _candidate = Field::<Circuit>::from_bits(&to_bits(&modulus_bits, &zero_bits), endianness);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure the largest field element, with a nonzero most significant excess bit, is rejected.
+            let _candidate = Field::<Circuit>::from_bits(&to_bits(&minus_one_bits, &nonzero_bits), endianness);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits() {
+        check_from_bits(Mode::Constant);
+        check_from_bits(Mode::Public);
+        check_from_bits(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_bits_out_of_range() {
+        check_from_bits_out_of_range(Endianness::Little);
+        check_from_bits_out_of_range(Endianness::Big);
+    }
+
+    #[test]
+    fn test_from_bits_over_length() {
+        check_from_bits_over_length(Endianness::Little);
+        check_from_bits_over_length(Endianness::Big);
+    }
+}
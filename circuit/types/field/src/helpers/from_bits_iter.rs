@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from an iterator of **little-endian** bits.
+    ///
+    /// As the range check inspects the bits beyond `E::BaseField::size_in_bits()`, and the excess bits
+    /// are enforced to be `0`s, the given bits are buffered in full before reconstructing the field element.
+    /// Thus, this method incurs the same costs as `from_bits_le`.
+    pub fn from_bits_le_iter<I: IntoIterator<Item = Boolean<E>>>(bits_le: I) -> Self {
+        // Buffer the bits, as the range check requires access to all of the bits.
+        let bits_le = bits_le.into_iter().collect::<Vec<_>>();
+        // Reconstruct the field element.
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_iter(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_iter(given_bits.iter().cloned());
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+
+            // Ensure the iterator path matches the slice path, including excess bits.
+            let given_bits = vec![given_bits, vec![Boolean::new(mode, false); i as usize]].concat();
+            let candidate = Field::<Circuit>::from_bits_le_iter(given_bits.clone());
+            let expected = Field::<Circuit>::from_bits_le(&given_bits);
+            assert_eq!(expected.eject_value(), candidate.eject_value());
+            assert_eq!(expected.eject_mode(), candidate.eject_mode());
+            assert_eq!(
+                expected.bits_le.get().expect("Caching failed").len(),
+                candidate.bits_le.get().expect("Caching failed").len()
+            );
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_iter_constant() {
+        check_from_bits_le_iter(Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_bits_le_iter_public() {
+        check_from_bits_le_iter(Mode::Public, 0, 0, 252, 253);
+    }
+
+    #[test]
+    fn test_from_bits_le_iter_private() {
+        check_from_bits_le_iter(Mode::Private, 0, 0, 252, 253);
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, as in `from_bits_le`,
+    /// and labels the variables and constraints it creates with the given provenance label.
+    ///
+    /// With the `debug_labels` feature, the reconstruction runs in a nested scope named `label`, so that
+    /// its constraints are attributed to the label when the circuit is printed, and its costs can be read
+    /// with the `*_in_scope` counters. Without the feature, this is equivalent to `from_bits_le`.
+    /// As scope names are period-separated, the label must not contain a period.
+    pub fn from_bits_le_labeled(bits_le: &[Boolean<E>], label: &'static str) -> Self {
+        #[cfg(feature = "debug_labels")]
+        {
+            E::scope(label, || Self::from_bits_le(bits_le))
+        }
+
+        #[cfg(not(feature = "debug_labels"))]
+        {
+            let _ = label;
+            Self::from_bits_le(bits_le)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits_le_labeled() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+                // Ensure the labeled value matches the unlabeled value, at the same cost.
+                let (candidate, candidate_counts) = Circuit::scope(format!("{mode} {i} labeled"), || {
+                    let candidate = Field::<Circuit>::from_bits_le_labeled(&given_bits, "from_bits_le_labeled");
+                    (candidate, (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope()))
+                });
+                let (default, default_counts) = Circuit::scope(format!("{mode} {i} default"), || {
+                    let default = Field::<Circuit>::from_bits_le(&given_bits);
+                    (default, (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope()))
+                });
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(default.eject_value(), candidate.eject_value());
+                assert_eq!(default_counts, candidate_counts);
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[cfg(feature = "debug_labels")]
+    #[test]
+    fn test_from_bits_le_labeled_records_label() {
+        let given: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut TestRng::default());
+        let given_bits = Field::<Circuit>::new(Mode::Private, given).to_bits_le();
+
+        // Ensure the constraints of the range check are attributed to the label.
+        Circuit::scope("outer", || {
+            let num_constraints_before = Circuit::num_constraints();
+            let candidate = Field::<Circuit>::from_bits_le_labeled(&given_bits, "provenance");
+            assert_eq!(given, candidate.eject_value());
+            assert!(Circuit::num_constraints() > num_constraints_before);
+        });
+        let output = format!("{}", Circuit);
+        assert!(output.contains("Constraint outer.provenance:"));
+        Circuit::reset();
+    }
+}
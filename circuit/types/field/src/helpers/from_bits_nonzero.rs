@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, and enforces it is nonzero.
+    ///
+    /// The field element is enforced to be nonzero by witnessing its inverse (as in `Field::inverse`),
+    /// which costs one additional private variable and one additional constraint, on top of `from_bits_le`.
+    ///
+    /// Note: A constant zero field element has no inverse, and will halt.
+    pub fn from_bits_le_nonzero(bits_le: &[Boolean<E>]) -> Self {
+        // Reconstruct the field element.
+        let output = Self::from_bits_le(bits_le);
+        // Ensure the field element is nonzero, as only a nonzero field element has an inverse.
+        let _inverse = output.inverse();
+        output
+    }
+}
+
+/// The operation of `Field::from_bits_le_nonzero`, which keys its `Metrics` and `OutputMode`.
+pub trait FromBitsNonzero {}
+
+impl<E: Environment> Metrics<dyn FromBitsNonzero> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        // The reconstruction is followed by an inverse of the reconstructed field element.
+        // The reconstructed field element is constant if and only if the bits up to the field size are constant.
+        let mode = match case.iter().take(E::BaseField::size_in_bits()).all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        count!(Field<E>, FromBits<Boolean = Boolean<E>>, case) + count!(Field<E>, Inverse<Output = Field<E>>, &mode)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn FromBitsNonzero> for Field<E> {
+    type Case = Vec<CircuitType<Boolean<E>>>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        output_mode!(Field<E>, FromBits<Boolean = Boolean<E>>, case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_nonzero(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random nonzero element.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            if expected.is_zero() {
+                continue;
+            }
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_nonzero(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                // Ensure the count includes the constraint on the inverse.
+                let modes = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+                assert_count!(Field<Circuit>, FromBitsNonzero, &modes);
+                let case = given_bits.iter().map(CircuitType::from).collect::<Vec<_>>();
+                assert_output_mode!(Field<Circuit>, FromBitsNonzero, &case, candidate);
+            });
+            Circuit::reset();
+        }
+
+        // Ensure a zero field element leaves the circuit unsatisfied.
+        // Note: A constant zero field element halts instead.
+        if !mode.is_constant() {
+            let given_bits = Field::<Circuit>::new(mode, console::Field::zero()).to_bits_le();
+            Circuit::scope(format!("{mode} zero"), || {
+                let candidate = Field::<Circuit>::from_bits_le_nonzero(&given_bits);
+                assert!(candidate.eject_value().is_zero());
+                let modes = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+                assert_count_fails!(Field<Circuit>, FromBitsNonzero, &modes);
+            });
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_nonzero_constant() {
+        check_from_bits_le_nonzero(Mode::Constant, 1, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_bits_le_nonzero_public() {
+        check_from_bits_le_nonzero(Mode::Public, 0, 0, 253, 254);
+    }
+
+    #[test]
+    fn test_from_bits_le_nonzero_private() {
+        check_from_bits_le_nonzero(Mode::Private, 0, 0, 253, 254);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bits_le_nonzero_constant_zero_fails() {
+        let given_bits = vec![Boolean::<Circuit>::constant(false); 8];
+        let _candidate = Field::<Circuit>::from_bits_le_nonzero(&given_bits);
+    }
+}
@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a pair of base field elements, `(low, high)`, from two lists of **little-endian** bits,
+    /// representing the two-limb value `low + high * 2^E::BaseField::size_in_bits()`.
+    ///
+    /// The low limb spans the full base field: if `low_bits_le` exceeds `E::BaseField::size_in_data_bits()`,
+    /// it is enforced to be less than `BaseField::MODULUS`, as in `from_bits_le`. The high limb is bounded
+    /// to `E::BaseField::size_in_data_bits()` bits, which ensures it is less than `BaseField::MODULUS` without
+    /// a range check. The excess bits of both limbs are enforced to be `0`s with a single combined assertion.
+    ///
+    /// Thus, when the high limb is decoded from `E::BaseField::size_in_bits()` bits (e.g. when splitting
+    /// a list of bits into two field-sized limbs), this method avoids the range check on the high limb,
+    /// and costs less than two independent calls to `from_bits_le`.
+    pub fn from_bits_le_pair(low_bits_le: &[Boolean<E>], high_bits_le: &[Boolean<E>]) -> (Self, Self) {
+        // Retrieve the data and base field size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        // Split each limb into its bits and its excess bits.
+        let (low_bits_le, low_excess_bits) = low_bits_le.split_at(low_bits_le.len().min(size_in_bits));
+        let (high_bits_le, high_excess_bits) = high_bits_le.split_at(high_bits_le.len().min(size_in_data_bits));
+
+        // Check if all excess bits of both limbs are zero.
+        let should_be_zero =
+            low_excess_bits.iter().chain(high_excess_bits).fold(Boolean::constant(false), |acc, bit| acc | bit);
+        // Ensure `should_be_zero` is zero.
+        should_be_zero.enforce_false();
+
+        // Reconstruct the low limb, which is range checked if it exceeds `size_in_data_bits`.
+        let low = Self::from_bits_le(low_bits_le);
+        // Reconstruct the high limb, which is never range checked, as it fits within `size_in_data_bits`.
+        let high = Self::from_bits_le(high_bits_le);
+
+        (low, high)
+    }
+
+    /// Initializes a pair of base field elements, `(low, high)`, from a single list of **little-endian** bits,
+    /// representing the wide integer `low + high * 2^E::BaseField::size_in_data_bits()`.
+    ///
+    /// The bits are split at `E::BaseField::size_in_data_bits()`: the low limb is reconstructed from
+    /// `bits_le[..size_in_data_bits]`, which fits within the base field without a range check, and the
+    /// high limb is reconstructed from `bits_le[size_in_data_bits..]`, as in `from_bits_le`. Thus, the high limb
+    /// is enforced to be less than `BaseField::MODULUS`, and the wide integer is enforced to be less than
+    /// `BaseField::MODULUS * 2^E::BaseField::size_in_data_bits()`, so that its `(low, high)` representation is unique.
+    pub fn from_bits_le_wide(bits_le: &[Boolean<E>]) -> (Self, Self) {
+        // Split the bits at `size_in_data_bits`.
+        let (low_bits_le, high_bits_le) = bits_le.split_at(bits_le.len().min(E::BaseField::size_in_data_bits()));
+
+        // Reconstruct the low limb, which is never range checked, as it fits within `size_in_data_bits`.
+        let low = Self::from_bits_le(low_bits_le);
+        // Reconstruct the high limb, which is range checked if it exceeds `size_in_data_bits`.
+        let high = Self::from_bits_le(high_bits_le);
+
+        (low, high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_pair(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+
+        for i in 0..ITERATIONS {
+            // Sample a random low limb.
+            let expected_low: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            // Sample a random high limb, as `size_in_data_bits` bits followed by a zero bit.
+            let high_bits = (0..size_in_data_bits).map(|_| Uniform::rand(&mut rng)).chain([false]).collect::<Vec<_>>();
+            let expected_high = console::Field::<<Circuit as Environment>::Network>::from_bits_le(&high_bits).unwrap();
+
+            // Add excess zero bits to both limbs.
+            let excess_bits = vec![false; i as usize];
+            let low_bits = [expected_low.to_bits_le(), excess_bits.clone()].concat();
+            let high_bits = [high_bits, excess_bits].concat();
+            let low_bits = low_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+            let high_bits = high_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let num_constraints_before = Circuit::num_constraints();
+                let (low, high) = Field::<Circuit>::from_bits_le_pair(&low_bits, &high_bits);
+                assert_eq!(expected_low, low.eject_value());
+                assert_eq!(expected_high, high.eject_value());
+                match mode.is_constant() {
+                    true => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                    // The combined excess bits (`2 * i` bits, and the top bit of the high limb) are folded
+                    // with one private variable and one constraint per bit after the first, and enforced to be zero.
+                    false => assert_scope!(num_constants, num_public, num_private + 2 * i, num_constraints + 2 * i + 1),
+                }
+                let num_combined_constraints = Circuit::num_constraints() - num_constraints_before;
+
+                // Ensure the combined constraint count is less than two independent calls to `from_bits_le`.
+                let num_constraints_before = Circuit::num_constraints();
+                assert_eq!(expected_low, Field::<Circuit>::from_bits_le(&low_bits).eject_value());
+                assert_eq!(expected_high, Field::<Circuit>::from_bits_le(&high_bits).eject_value());
+                let num_independent_constraints = Circuit::num_constraints() - num_constraints_before;
+                match mode.is_constant() {
+                    true => assert_eq!(num_combined_constraints, num_independent_constraints),
+                    false => assert!(num_combined_constraints < num_independent_constraints),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_pair_constant() {
+        check_from_bits_le_pair(Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_bits_le_pair_public() {
+        check_from_bits_le_pair(Mode::Public, 0, 0, 252, 253);
+    }
+
+    #[test]
+    fn test_from_bits_le_pair_private() {
+        check_from_bits_le_pair(Mode::Private, 0, 0, 252, 253);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bits_le_pair_high_limb_out_of_bounds() {
+        // Ensure a high limb that exceeds `size_in_data_bits` bits is rejected.
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let mut high_bits = vec![Boolean::<Circuit>::constant(false); size_in_data_bits];
+        high_bits.push(Boolean::constant(true));
+        let _ = Field::<Circuit>::from_bits_le_pair(&[], &high_bits);
+    }
+
+    fn check_from_bits_le_wide(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for num_bits in [0, 1, size_in_data_bits, size_in_bits, 400, 2 * size_in_data_bits, 2 * size_in_data_bits + 1] {
+            // Sample the bits, where the high limb is sampled as a field element if it spans `size_in_bits` bits.
+            let mut given_bits =
+                (0..num_bits.min(2 * size_in_data_bits)).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            if num_bits > 2 * size_in_data_bits {
+                let high: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                given_bits.truncate(size_in_data_bits);
+                given_bits.extend(high.to_bits_le());
+            }
+            // Compute the expected limbs natively.
+            let split = num_bits.min(size_in_data_bits);
+            let expected_low =
+                console::Field::<<Circuit as Environment>::Network>::from_bits_le(&given_bits[..split]).unwrap();
+            let expected_high =
+                console::Field::<<Circuit as Environment>::Network>::from_bits_le(&given_bits[split..]).unwrap();
+
+            let candidate_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+            Circuit::scope(format!("{mode} {num_bits}"), || {
+                let (low, high) = Field::<Circuit>::from_bits_le_wide(&candidate_bits);
+                assert_eq!(expected_low, low.eject_value());
+                assert_eq!(expected_high, high.eject_value());
+                // Ensure the wide integer is recombined from the limbs.
+                let two_to_the_split = (0..split).fold(console::Field::one(), |acc, _| acc.double());
+                let recombined = candidate_bits.iter().rev().fold(console::Field::zero(), |acc, bit| {
+                    acc.double() + console::Field::from_u8(bit.eject_value() as u8)
+                });
+                assert_eq!(recombined, expected_low + expected_high * two_to_the_split);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+
+        // Ensure a high limb of `BaseField::MODULUS` is rejected.
+        if !mode.is_constant() {
+            let mut modulus_bits_le = (-<Circuit as Environment>::BaseField::one()).to_bits_le();
+            modulus_bits_le[0] = true;
+            let given_bits = [vec![false; size_in_data_bits], modulus_bits_le].concat();
+            let candidate_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+            let _ = Field::<Circuit>::from_bits_le_wide(&candidate_bits);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_wide() {
+        check_from_bits_le_wide(Mode::Constant);
+        check_from_bits_le_wide(Mode::Public);
+        check_from_bits_le_wide(Mode::Private);
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, given the parameters
+    /// of the base field explicitly. `from_bits_le` delegates to this method with the parameters of `E::BaseField`.
+    ///
+    /// As the field arithmetic is fixed by `E`, the parameters must match those of `E::BaseField`,
+    /// i.e. `E::BaseField::size_in_bits()`, `E::BaseField::size_in_data_bits()`, and `BaseField::MODULUS - 1`.
+    /// Returns an error if any of the given parameters differ, so that a codec which is parameterized at runtime
+    /// fails loudly when paired with the wrong environment, instead of reconstructing with mismatched widths.
+    pub fn from_bits_le_with_params(
+        bits_le: &[Boolean<E>],
+        size_in_bits: usize,
+        size_in_data_bits: usize,
+        modulus_minus_one: E::BaseField,
+    ) -> Result<Self> {
+        // Ensure the given parameters match the base field.
+        ensure!(
+            size_in_bits == E::BaseField::size_in_bits(),
+            "Expected a field size of {} bits, found {size_in_bits}",
+            E::BaseField::size_in_bits()
+        );
+        ensure!(
+            size_in_data_bits == E::BaseField::size_in_data_bits(),
+            "Expected a field data size of {} bits, found {size_in_data_bits}",
+            E::BaseField::size_in_data_bits()
+        );
+        ensure!(modulus_minus_one == -E::BaseField::one(), "The given modulus does not match the base field modulus");
+
+        // Reconstruct the field element.
+        let (output, _) = Self::from_bits_le_with_range_witness_and_policy::<HaltPolicy>(bits_le)?;
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits_le_with_params() {
+        let mut rng = TestRng::default();
+
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let modulus_minus_one = -<Circuit as Environment>::BaseField::one();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+                // Ensure the real parameters match the default, at the same cost.
+                let (candidate, candidate_counts) = Circuit::scope(format!("{mode} {i} params"), || {
+                    let candidate = Field::<Circuit>::from_bits_le_with_params(
+                        &given_bits,
+                        size_in_bits,
+                        size_in_data_bits,
+                        modulus_minus_one,
+                    )
+                    .unwrap();
+                    (candidate, (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope()))
+                });
+                let (default, default_counts) = Circuit::scope(format!("{mode} {i} default"), || {
+                    let default = Field::<Circuit>::from_bits_le(&given_bits);
+                    (default, (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope()))
+                });
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(default.eject_value(), candidate.eject_value());
+                assert_eq!(default_counts, candidate_counts);
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_with_params_mismatch_fails() {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let modulus_minus_one = -<Circuit as Environment>::BaseField::one();
+        let modulus_minus_two = modulus_minus_one - <Circuit as Environment>::BaseField::one();
+        let given_bits = vec![Boolean::<Circuit>::new(Mode::Private, true); 8];
+
+        // Ensure a mismatched size is rejected.
+        let candidate = Field::<Circuit>::from_bits_le_with_params(
+            &given_bits,
+            size_in_bits + 1,
+            size_in_data_bits,
+            modulus_minus_one,
+        );
+        assert!(candidate.unwrap_err().to_string().contains("Expected a field size of"));
+
+        // Ensure a mismatched data size is rejected.
+        let candidate = Field::<Circuit>::from_bits_le_with_params(
+            &given_bits,
+            size_in_bits,
+            size_in_data_bits - 1,
+            modulus_minus_one,
+        );
+        assert!(candidate.unwrap_err().to_string().contains("Expected a field data size of"));
+
+        // Ensure a mismatched modulus is rejected.
+        let candidate =
+            Field::<Circuit>::from_bits_le_with_params(&given_bits, size_in_bits, size_in_data_bits, modulus_minus_two);
+        assert!(candidate.unwrap_err().to_string().contains("The given modulus does not match the base field modulus"));
+
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, as in `from_bits_le`,
+    /// where the error policy `P` decides how corrupt internal state is handled.
+    ///
+    /// With `HaltPolicy`, this halts on corrupt internal state, as `from_bits_le` does. With `AbortPolicy`,
+    /// this returns an error instead, so that callers which must not panic can recover.
+    /// Note: Constant bits that are not less than `BaseField::MODULUS` are a caller error, and halt with either policy.
+    pub fn from_bits_le_with_policy<P: ErrorPolicy>(bits_le: &[Boolean<E>]) -> Result<Self> {
+        Self::from_bits_le_with_range_witness_and_policy::<P>(bits_le).map(|(output, _)| output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_with_policy(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+            // Ensure both policies reconstruct the same element as `from_bits_le`, with the same costs.
+            let expected_costs = Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+            });
+            let halt_costs = Circuit::scope(format!("{mode} halt {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_with_policy::<HaltPolicy>(&given_bits).unwrap();
+                assert_eq!(expected, candidate.eject_value());
+                (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+            });
+            let abort_costs = Circuit::scope(format!("{mode} abort {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_with_policy::<AbortPolicy>(&given_bits).unwrap();
+                assert_eq!(expected, candidate.eject_value());
+                (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+            });
+            assert_eq!(expected_costs, halt_costs);
+            assert_eq!(expected_costs, abort_costs);
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_with_policy() {
+        check_from_bits_le_with_policy(Mode::Constant);
+        check_from_bits_le_with_policy(Mode::Public);
+        check_from_bits_le_with_policy(Mode::Private);
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits,
+    /// and returns the number of excess bits (beyond `E::BaseField::size_in_bits()`) that were enforced to be `0`s.
+    ///
+    /// This method is purely informational, and incurs the same costs as `from_bits_le`.
+    pub fn from_bits_le_reporting(bits_le: &[Boolean<E>]) -> (Self, usize) {
+        // Compute the number of excess bits.
+        let num_excess_bits = bits_le.len().saturating_sub(E::BaseField::size_in_bits());
+        // Return the field element and the number of excess bits.
+        (Self::from_bits_le(bits_le), num_excess_bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_reporting(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+            // Add excess zero bits.
+            let candidate = vec![given_bits.clone(), vec![Boolean::new(mode, false); i as usize]].concat();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let (candidate, num_excess_bits) = Field::<Circuit>::from_bits_le_reporting(&candidate);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(i as usize, num_excess_bits);
+            });
+
+            // Ensure inputs without excess bits report no excess bits.
+            let (candidate, num_excess_bits) = Field::<Circuit>::from_bits_le_reporting(&given_bits[..i as usize]);
+            assert_eq!(0, num_excess_bits);
+            assert_eq!(
+                Field::<Circuit>::from_bits_le(&given_bits[..i as usize]).eject_value(),
+                candidate.eject_value()
+            );
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_reporting() {
+        check_from_bits_le_reporting(Mode::Constant);
+        check_from_bits_le_reporting(Mode::Public);
+        check_from_bits_le_reporting(Mode::Private);
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, where a list of bits
+    /// that is greater than or equal to `BaseField::MODULUS` is clamped to `BaseField::MODULUS - 1`.
+    ///
+    /// Unlike `from_bits_le`, an out-of-range list of bits (including nonzero excess bits) does not
+    /// cause the circuit to be unsatisfied. Instead, the result of the range comparison is used to select
+    /// between the reconstructed value and `-1`, which costs one additional private variable and constraint.
+    pub fn from_bits_le_saturating(bits_le: &[Boolean<E>]) -> Self {
+        // Retrieve the data and base field size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        // If the bits fit within `size_in_data_bits`, the value is guaranteed to be less than `BaseField::MODULUS`.
+        if bits_le.len() <= size_in_data_bits {
+            return Self::from_bits_le(bits_le);
+        }
+
+        // Check if any of the excess bits are set.
+        let is_excess_nonzero = bits_le[size_in_bits..].iter().fold(Boolean::constant(false), |acc, bit| acc | bit);
+
+        // Note: As `size_in_bits` is `size_in_data_bits + 1`, `bits_le` contains at least `size_in_bits` bits here.
+        let bits_le = &bits_le[..size_in_bits];
+
+        // Determine if the value is less than `BaseField::MODULUS`.
+        let is_in_range = Self::is_less_than_modulus(bits_le) & !is_excess_nonzero;
+
+        // Reconstruct the bits as a linear combination representing the (possibly out-of-range) value.
+        // `output` := (2^i * b_i + ... + 2^0 * b_0)
+        let mut output = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in bits_le {
+            output += Field::from_boolean(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+
+        // Return the value if it is in range, and `BaseField::MODULUS - 1` otherwise.
+        Self::ternary(&is_in_range, &output, &-Field::one())
+    }
+}
+
+/// The operation of `Field::from_bits_le_saturating`, which keys its `Metrics` and `OutputMode`.
+pub trait FromBitsSaturating {}
+
+impl<E: Environment> Metrics<dyn FromBitsSaturating> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn count(modes: &Self::Case) -> Count {
+        // Retrieve the data and base field size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        // If the bits fit within `size_in_data_bits`, the field element is reconstructed as in `from_bits_le`.
+        if modes.len() <= size_in_data_bits {
+            return count!(Field<E>, FromBits<Boolean = Boolean<E>>, modes);
+        }
+
+        // Count the number of non-constant bits within, and in excess of, the base field size.
+        let num_variable_bits = modes.iter().take(size_in_bits).filter(|mode| !mode.is_constant()).count() as u64;
+        let num_excess_bits = modes.iter().skip(size_in_bits).filter(|mode| !mode.is_constant()).count() as u64;
+
+        // The excess bits are folded with `num_excess_bits - 1` OR gates, but are not enforced to be zero.
+        let excess_count = match num_excess_bits {
+            0 => Count::zero(),
+            num_excess_bits => Count::is(0, 0, num_excess_bits - 1, num_excess_bits - 1),
+        };
+
+        // The range comparison uses at most one gate per non-constant bit.
+        let range_check_count = match num_variable_bits {
+            0 => Count::zero(),
+            num_variable_bits => Count::less_than(0, 0, num_variable_bits, num_variable_bits),
+        };
+
+        // The range comparison is combined with the excess bits using at most one AND gate,
+        // and selects between the reconstructed field element and `-1` using at most one ternary.
+        let select_count = match (num_variable_bits, num_excess_bits) {
+            (0, 0) => Count::zero(),
+            (0, _) | (_, 0) => Count::less_than(0, 0, 1, 1),
+            (_, _) => Count::less_than(0, 0, 2, 2),
+        };
+
+        excess_count + range_check_count + select_count
+    }
+}
+
+impl<E: Environment> OutputMode<dyn FromBitsSaturating> for Field<E> {
+    type Case = Vec<CircuitType<Boolean<E>>>;
+
+    /// Returns the mode of the saturated field element, given the bits.
+    ///
+    /// If the comparison with the modulus is constant, the output is either the reconstructed field element,
+    /// or the constant `-1`. Otherwise, the output is selected by a non-constant condition, and is `Private`.
+    fn output_mode(case: &Self::Case) -> Mode {
+        // Retrieve the data and base field size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        // If the bits fit within `size_in_data_bits`, the field element is reconstructed as in `from_bits_le`.
+        if case.len() <= size_in_data_bits {
+            return output_mode!(Field<E>, FromBits<Boolean = Boolean<E>>, case);
+        }
+
+        // Returns the value of the given bit, if it is constant.
+        let constant = |bit: &CircuitType<Boolean<E>>| match bit {
+            CircuitType::Constant(constant) => Some(constant.eject_value()),
+            _ => None,
+        };
+        // Returns the value of the AND (resp. OR) of the given bits, if it is constant, as in `Boolean`.
+        let and = |a: Option<bool>, b: Option<bool>| match (a, b) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), bit) | (bit, Some(true)) => bit,
+            (None, None) => None,
+        };
+        let or = |a: Option<bool>, b: Option<bool>| match (a, b) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), bit) | (bit, Some(false)) => bit,
+            (None, None) => None,
+        };
+
+        // Determine if any of the excess bits are set, if it is constant.
+        let is_excess_nonzero = case[size_in_bits..].iter().fold(Some(false), |acc, bit| or(acc, constant(bit)));
+
+        // Determine if the value is greater than `BaseField::MODULUS - 1`, if it is constant, as in `RangeWitness`.
+        let is_greater = (-E::BaseField::one()).to_bits_le().iter().zip(&case[..size_in_bits]).fold(
+            Some(false),
+            |rest, (this, that)| match this {
+                true => and(constant(that), rest),
+                false => or(constant(that), rest),
+            },
+        );
+
+        // Determine the mode of the selected field element.
+        match and(is_greater.map(|bit| !bit), is_excess_nonzero.map(|bit| !bit)) {
+            // The reconstructed field element is selected.
+            Some(true) => output_mode!(Field<E>, FromBits<Boolean = Boolean<E>>, case),
+            // The constant `-1` is selected.
+            Some(false) => Mode::Constant,
+            // The field element is selected by a non-constant condition.
+            None => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_saturating(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+            // Ensure in-range bits pass through.
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_saturating(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                let modes = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+                assert_count!(Field<Circuit>, FromBitsSaturating, &modes);
+                let case = given_bits.iter().map(CircuitType::from).collect::<Vec<_>>();
+                assert_output_mode!(Field<Circuit>, FromBitsSaturating, &case, candidate);
+            });
+            Circuit::reset();
+        }
+
+        // Construct the bits of `BaseField::MODULUS`, by setting the least significant bit of `BaseField::MODULUS - 1`.
+        let mut modulus_bits_le = (-<Circuit as Environment>::BaseField::one()).to_bits_le();
+        modulus_bits_le[0] = true;
+        let size_in_bits = modulus_bits_le.len();
+
+        // Ensure out-of-range bits are clamped.
+        for candidate in [
+            modulus_bits_le.clone(),
+            vec![true; size_in_bits],
+            [vec![false; size_in_bits], vec![true]].concat(),
+            [modulus_bits_le, vec![false]].concat(),
+        ] {
+            let given_bits = candidate.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+            Circuit::scope(format!("{mode} saturated"), || {
+                let candidate = Field::<Circuit>::from_bits_le_saturating(&given_bits);
+                assert_eq!(-console::Field::<<Circuit as Environment>::Network>::one(), candidate.eject_value());
+
+                let modes = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+                assert_count!(Field<Circuit>, FromBitsSaturating, &modes);
+                let case = given_bits.iter().map(CircuitType::from).collect::<Vec<_>>();
+                assert_output_mode!(Field<Circuit>, FromBitsSaturating, &case, candidate);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_saturating_constant() {
+        check_from_bits_le_saturating(Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_bits_le_saturating_public() {
+        check_from_bits_le_saturating(Mode::Public, 0, 0, 253, 253);
+    }
+
+    #[test]
+    fn test_from_bits_le_saturating_private() {
+        check_from_bits_le_saturating(Mode::Private, 0, 0, 253, 253);
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, as in `from_bits_le`,
+    /// and returns the native partial sums of the reconstruction, for debugging.
+    ///
+    /// The `i`-th partial sum is `(2^i * b_i + ... + 2^0 * b_0)`, for each of the first
+    /// `min(bits_le.len(), E::BaseField::size_in_bits())` bits. The partial sums are computed
+    /// from the values of the bits, and do not add any constraints.
+    pub fn from_bits_le_trace(bits_le: &[Boolean<E>]) -> (Self, Vec<E::BaseField>) {
+        // Reconstruct the field element.
+        let output = Self::from_bits_le(bits_le);
+
+        // Compute the partial sums from the values of the bits.
+        let mut partial_sum = E::BaseField::zero();
+        let mut coefficient = E::BaseField::one();
+        let trace = bits_le
+            .iter()
+            .take(E::BaseField::size_in_bits())
+            .map(|bit| {
+                if bit.eject_value() {
+                    partial_sum += coefficient;
+                }
+                coefficient = coefficient.double();
+                partial_sum
+            })
+            .collect();
+
+        (output, trace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_from_bits_le_trace(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for num_bits in [0, 1, 64, size_in_bits, size_in_bits + 3] {
+            // Sample a random element, and resize its bits.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let mut given_bits = expected.to_bits_le();
+            given_bits.resize(num_bits, false);
+            let candidate_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            // Ensure the trace does not add any cost.
+            let expected_count = Circuit::scope(format!("{mode} {num_bits}"), || {
+                let _candidate = Field::<Circuit>::from_bits_le(&candidate_bits);
+                (
+                    Circuit::num_constants_in_scope(),
+                    Circuit::num_private_in_scope(),
+                    Circuit::num_constraints_in_scope(),
+                )
+            });
+            let (candidate, trace) = Circuit::scope(format!("Trace {mode} {num_bits}"), || {
+                let (candidate, trace) = Field::<Circuit>::from_bits_le_trace(&candidate_bits);
+                let count = (
+                    Circuit::num_constants_in_scope(),
+                    Circuit::num_private_in_scope(),
+                    Circuit::num_constraints_in_scope(),
+                );
+                assert_eq!(expected_count, count);
+                (candidate, trace)
+            });
+
+            // Ensure the trace has one partial sum per reconstructed bit, ending in the output.
+            assert_eq!(num_bits.min(size_in_bits), trace.len());
+            match trace.last() {
+                Some(last) => assert_eq!(*candidate.eject_value(), *last),
+                None => assert!(candidate.eject_value().is_zero()),
+            }
+            // Ensure each partial sum is the sum of the preceding partial sum and the weighted bit.
+            let mut coefficient = <Circuit as Environment>::BaseField::one();
+            let mut previous = <Circuit as Environment>::BaseField::zero();
+            for (partial_sum, bit) in trace.iter().zip(&given_bits) {
+                assert_eq!(*partial_sum, if *bit { previous + coefficient } else { previous });
+                coefficient = coefficient.double();
+                previous = *partial_sum;
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_trace() {
+        check_from_bits_le_trace(Mode::Constant);
+        check_from_bits_le_trace(Mode::Public);
+        check_from_bits_le_trace(Mode::Private);
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, as in `from_bits_le`,
+    /// and returns an error if the constraints it adds are not satisfied by the current assignment.
+    ///
+    /// The excess bits and the range check are enforced in separate scopes, named `excess_bits` and
+    /// `range_check`, so that the error identifies the failing check, and the failing constraint is
+    /// reported with its scope. This catches malformed bits immediately, rather than when proving.
+    /// This method costs the same as `from_bits_le`.
+    pub fn from_bits_le_verified(bits_le: &[Boolean<E>]) -> Result<Self> {
+        // Split the bits into the bits up to `size_in_bits`, and the excess bits.
+        let (bits_le, excess_bits) = bits_le.split_at(bits_le.len().min(E::BaseField::size_in_bits()));
+
+        // Ensure the excess bits are zero.
+        let is_satisfied = E::scope("excess_bits", || {
+            if !excess_bits.is_empty() {
+                excess_bits.iter().fold(Boolean::constant(false), |acc, bit| acc | bit).enforce_false();
+            }
+            E::is_satisfied_in_scope()
+        });
+        ensure!(is_satisfied, "Unsatisfied constraint in 'excess_bits': the excess bits of the field are not zero");
+
+        // Reconstruct the field element, and ensure it is less than `BaseField::MODULUS`.
+        let (output, is_satisfied) = E::scope("range_check", || {
+            let output = Self::from_bits_le(bits_le);
+            (output, E::is_satisfied_in_scope())
+        });
+        ensure!(is_satisfied, "Unsatisfied constraint in 'range_check': the bits are not less than the modulus");
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_verified(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let mut given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+            given_bits.extend((0..i % 4).map(|_| Boolean::new(mode, false)));
+
+            // Ensure the value and cost match `from_bits_le`.
+            let (candidate, candidate_counts) = Circuit::scope(format!("{mode} {i} verified"), || {
+                let candidate = Field::<Circuit>::from_bits_le_verified(&given_bits).unwrap();
+                (candidate, (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope()))
+            });
+            let (default, default_counts) = Circuit::scope(format!("{mode} {i} default"), || {
+                let default = Field::<Circuit>::from_bits_le(&given_bits);
+                (default, (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope()))
+            });
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(default.eject_value(), candidate.eject_value());
+            assert_eq!(default_counts, candidate_counts);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+
+        if !mode.is_constant() {
+            // Ensure `BaseField::MODULUS` is rejected by the range check.
+            let mut modulus_bits_le = (-<Circuit as Environment>::BaseField::one()).to_bits_le();
+            modulus_bits_le[0] = true;
+            let given_bits = modulus_bits_le.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+            let error = Field::<Circuit>::from_bits_le_verified(&given_bits).unwrap_err();
+            assert!(error.to_string().contains("'range_check'"));
+            Circuit::reset();
+
+            // Ensure a nonzero excess bit is rejected.
+            let mut given_bits = Field::<Circuit>::new(mode, Uniform::rand(&mut rng)).to_bits_le();
+            given_bits.push(Boolean::new(mode, true));
+            let error = Field::<Circuit>::from_bits_le_verified(&given_bits).unwrap_err();
+            assert!(error.to_string().contains("'excess_bits'"));
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_verified() {
+        check_from_bits_le_verified(Mode::Constant);
+        check_from_bits_le_verified(Mode::Public);
+        check_from_bits_le_verified(Mode::Private);
+    }
+}
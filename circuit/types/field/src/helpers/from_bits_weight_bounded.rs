@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, as in `from_bits_le`,
+    /// and enforces that at most `max_weight` of the bits are set.
+    ///
+    /// The weight of the bits is their sum, which is free. It is enforced to be at most `max_weight` by
+    /// decomposing `weight + (2^k - 1 - max_weight)` into `k` bits, where `k` is the bit length of `max_weight`.
+    /// This costs `k` private variables and `k + 1` constraints, on top of `from_bits_le`, and is skipped
+    /// if `max_weight` is at least the number of bits.
+    pub fn from_bits_le_weight_bounded(bits_le: &[Boolean<E>], max_weight: usize) -> Self {
+        // Reconstruct the field element.
+        let output = Self::from_bits_le(bits_le);
+
+        // If the weight can not exceed `max_weight`, the bound is trivially satisfied.
+        if max_weight >= bits_le.len() {
+            return output;
+        }
+
+        // Compute the weight of the bits.
+        let weight = bits_le.iter().fold(Field::zero(), |weight, bit| weight + Field::from_boolean(bit));
+
+        // Offset the weight, so that it fits within `k` bits if and only if it is at most `max_weight`.
+        // Note: As the weight is at most the number of bits, the offset weight does not wrap around the modulus.
+        let num_bits = (usize::BITS - max_weight.leading_zeros()) as usize;
+        let offset = ((1u128 << num_bits) - 1) - max_weight as u128;
+        let offset_weight = weight + Field::from(E::one() * E::BaseField::from(offset));
+
+        // Ensure the offset weight fits within `k` bits.
+        offset_weight.to_lower_bits_le(num_bits);
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_weight_bounded(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample random bits, which fit within `size_in_data_bits` and are not range checked.
+            let given_bits = (0..64).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let weight = given_bits.iter().filter(|bit| **bit).count();
+            let expected = console::Field::<<Circuit as Environment>::Network>::from_bits_le(&given_bits).unwrap();
+            let candidate_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            // Ensure a bound of the exact weight is satisfied.
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_weight_bounded(&candidate_bits, weight);
+                assert_eq!(expected, candidate.eject_value());
+                let num_bits = (usize::BITS - weight.leading_zeros()) as u64;
+                match mode.is_constant() {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, num_bits, num_bits + 1),
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure a bound of at least the number of bits is free.
+            Circuit::scope(format!("{mode} {i} trivial"), || {
+                let _candidate = Field::<Circuit>::from_bits_le_weight_bounded(&candidate_bits, 64);
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+
+            // Ensure a bound below the weight is not satisfied.
+            if !mode.is_constant() && weight > 0 {
+                let _candidate = Field::<Circuit>::from_bits_le_weight_bounded(&candidate_bits, weight - 1);
+                assert!(!Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+
+        // Ensure a bound of zero only admits zero.
+        if !mode.is_constant() {
+            let candidate_bits = vec![Boolean::<Circuit>::new(mode, false); 8];
+            let _candidate = Field::<Circuit>::from_bits_le_weight_bounded(&candidate_bits, 0);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+
+            let mut candidate_bits = candidate_bits;
+            candidate_bits[7] = Boolean::new(mode, true);
+            let _candidate = Field::<Circuit>::from_bits_le_weight_bounded(&candidate_bits, 0);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_weight_bounded() {
+        check_from_bits_le_weight_bounded(Mode::Constant);
+        check_from_bits_le_weight_bounded(Mode::Public);
+        check_from_bits_le_weight_bounded(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bits_le_weight_bounded_constant_fails() {
+        let given_bits = vec![Boolean::<Circuit>::constant(true); 8];
+        let _candidate = Field::<Circuit>::from_bits_le_weight_bounded(&given_bits, 7);
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, with a carry-out bit.
+    ///
+    /// If `bits_le` is exactly one bit longer than `E::BaseField::size_in_bits()` (e.g. the sum of two
+    /// field-sized bit vectors), the most significant bit is returned as the carry, instead of being
+    /// enforced to be `0`, and the remaining bits are reconstructed as in `from_bits_le`.
+    /// Otherwise, the bits are reconstructed as in `from_bits_le`, and the carry is constant `false`.
+    ///
+    /// This method incurs the same costs as `from_bits_le` on the reconstructed bits, as the carry is free.
+    pub fn from_bits_le_with_carry(bits_le: &[Boolean<E>]) -> (Self, Boolean<E>) {
+        match bits_le.split_last() {
+            // If there is exactly one excess bit, return it as the carry.
+            Some((carry, bits_le)) if bits_le.len() == E::BaseField::size_in_bits() => {
+                (Self::from_bits_le(bits_le), carry.clone())
+            }
+            // Otherwise, reconstruct the field element as usual.
+            _ => (Self::from_bits_le(bits_le), Boolean::constant(false)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_with_carry(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element, and a random carry.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let expected_carry: bool = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+
+            // Ensure the carry is returned, and the low bits are reconstructed.
+            let candidate = [given_bits.clone(), vec![Boolean::new(mode, expected_carry)]].concat();
+            Circuit::scope(format!("{mode} {i}"), || {
+                let (candidate, carry) = Field::<Circuit>::from_bits_le_with_carry(&candidate);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(expected_carry, carry.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+
+            // Ensure the carry is constant `false` without an excess bit.
+            Circuit::scope(format!("No carry {mode} {i}"), || {
+                let (candidate, carry) = Field::<Circuit>::from_bits_le_with_carry(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(carry.is_constant() && !carry.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+
+            // Ensure more than one excess bit falls back to enforcing the excess bits are zero.
+            if !mode.is_constant() {
+                let candidate = [given_bits, vec![Boolean::new(mode, false), Boolean::new(mode, true)]].concat();
+                let (candidate, carry) = Field::<Circuit>::from_bits_le_with_carry(&candidate);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(carry.is_constant() && !carry.eject_value());
+                assert!(!Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_with_carry_constant() {
+        check_from_bits_le_with_carry(Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_bits_le_with_carry_public() {
+        check_from_bits_le_with_carry(Mode::Public, 0, 0, 252, 253);
+    }
+
+    #[test]
+    fn test_from_bits_le_with_carry_private() {
+        check_from_bits_le_with_carry(Mode::Private, 0, 0, 252, 253);
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits,
+    /// and enforces its parity (i.e. its least significant bit) is `expected_parity`.
+    ///
+    /// This method is equivalent to `from_bits_le`, followed by a check on the least significant bit
+    /// (where `true` is odd and `false` is even), which costs one additional constraint if the bit is not constant.
+    ///
+    /// Note: A constant bit with the wrong parity will halt.
+    pub fn from_bits_le_with_parity(bits_le: &[Boolean<E>], expected_parity: bool) -> Self {
+        // Reconstruct the field element.
+        let output = Self::from_bits_le(bits_le);
+
+        // Retrieve the least significant bit, which is `false` if there are no bits.
+        let parity = bits_le.first().cloned().unwrap_or_else(|| Boolean::constant(false));
+        // Ensure the least significant bit matches the expected parity.
+        match expected_parity {
+            true => parity.enforce_true(),
+            false => parity.enforce_false(),
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_with_parity(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+            let expected_parity = given_bits[0].eject_value();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_with_parity(&given_bits, expected_parity);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(expected_parity, candidate.parity().eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure the wrong parity leaves the circuit unsatisfied.
+            // Note: A constant bit with the wrong parity halts instead.
+            if !mode.is_constant() {
+                let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+                let candidate = Field::<Circuit>::from_bits_le_with_parity(&given_bits, !expected_parity);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(!Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_with_parity_constant() {
+        check_from_bits_le_with_parity(Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_bits_le_with_parity_public() {
+        check_from_bits_le_with_parity(Mode::Public, 0, 0, 252, 254);
+    }
+
+    #[test]
+    fn test_from_bits_le_with_parity_private() {
+        check_from_bits_le_with_parity(Mode::Private, 0, 0, 252, 254);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bits_le_with_parity_constant_fails() {
+        // Ensure a constant odd element with an even parity is rejected.
+        let given_bits = Field::<Circuit>::one().to_bits_le();
+        let _ = Field::<Circuit>::from_bits_le_with_parity(&given_bits, false);
+    }
+}
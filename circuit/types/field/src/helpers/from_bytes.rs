@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new constant base field element from a list of **little-endian** bytes.
+    ///
+    /// The bytes are decoded into bits with the least significant byte first, and within each byte,
+    /// the least significant bit first, i.e. bit `i` of byte `j` is bit `8 * j + i` of the field element.
+    /// The bits are then reconstructed as in `from_bits_le`, which halts if the bytes represent
+    /// a value that is greater than or equal to `BaseField::MODULUS`.
+    pub fn from_bytes_le(bytes_le: &[u8]) -> Self {
+        // Decode the bytes into constant little-endian bits.
+        let bits_le = bytes_le
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| Boolean::constant((byte >> i) & 1 == 1)))
+            .collect::<Vec<_>>();
+        // Reconstruct the field element.
+        Self::from_bits_le(&bits_le)
+    }
+
+    /// Initializes a new constant base field element from a list of **big-endian** bytes.
+    ///
+    /// The most significant byte is first, i.e. this is equivalent to `from_bytes_le` on the reversed bytes.
+    /// The bits within each byte are unaffected by the byte order.
+    pub fn from_bytes_be(bytes_be: &[u8]) -> Self {
+        // Reverse the given bytes from big-endian into little-endian.
+        let mut bytes_le = bytes_be.to_vec();
+        bytes_le.reverse();
+
+        Self::from_bytes_le(&bytes_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bytes() {
+        let mut rng = TestRng::default();
+
+        let size_in_bytes = (<Circuit as Environment>::BaseField::size_in_bits() + 7) / 8;
+
+        for num_bytes in [0, 1, 8, size_in_bytes - 1, size_in_bytes, size_in_bytes + 1] {
+            for i in 0..ITERATIONS {
+                // Sample random bytes, which are less than the modulus, with zero excess bytes.
+                let value: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let mut bytes_le = value
+                    .to_bits_le()
+                    .chunks(8)
+                    .map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8)))
+                    .collect::<Vec<_>>();
+                bytes_le.resize(num_bytes, 0u8);
+                let mut bytes_be = bytes_le.clone();
+                bytes_be.reverse();
+
+                // Compute the expected value natively.
+                let expected = <Circuit as Environment>::BaseField::from_bytes_le_mod_order(&bytes_le);
+                // Compute the expected value from manually-constructed bits.
+                let given_bits = bytes_le
+                    .iter()
+                    .flat_map(|byte| (0..8).map(move |j| Boolean::<Circuit>::constant((byte >> j) & 1 == 1)))
+                    .collect::<Vec<_>>();
+
+                Circuit::scope(format!("{num_bytes} {i}"), || {
+                    let candidate = Field::<Circuit>::from_bytes_le(&bytes_le);
+                    assert_eq!(expected, *candidate.eject_value());
+                    assert_eq!(Field::<Circuit>::from_bits_le(&given_bits).eject_value(), candidate.eject_value());
+                    assert!(candidate.is_constant());
+
+                    let candidate = Field::<Circuit>::from_bytes_be(&bytes_be);
+                    assert_eq!(expected, *candidate.eject_value());
+                    assert!(candidate.is_constant());
+                    assert_scope!(0, 0, 0, 0);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_be_order() {
+        // Ensure the most significant byte is first.
+        let candidate = Field::<Circuit>::from_bytes_be(&[0x01, 0x02]);
+        assert_eq!(console::Field::from_u16(0x0102), candidate.eject_value());
+        let candidate = Field::<Circuit>::from_bytes_le(&[0x01, 0x02]);
+        assert_eq!(console::Field::from_u16(0x0201), candidate.eject_value());
+    }
+
+    #[test]
+    #[should_panic(expected = "constant field bits exceed modulus")]
+    fn test_from_bytes_le_out_of_range_fails() {
+        // Ensure `BaseField::MODULUS`, at the field byte width, halts.
+        let mut modulus_bits_le = (-<Circuit as Environment>::BaseField::one()).to_bits_le();
+        // Set the least significant bit, as `MODULUS - 1` is even.
+        modulus_bits_le[0] = true;
+        let bytes_le = modulus_bits_le
+            .chunks(8)
+            .map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8)))
+            .collect::<Vec<_>>();
+        assert_eq!((<Circuit as Environment>::BaseField::size_in_bits() + 7) / 8, bytes_le.len());
+        let _candidate = Field::<Circuit>::from_bytes_le(&bytes_le);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bytes_le_excess_byte_fails() {
+        // Ensure a nonzero byte beyond the field byte width halts.
+        let size_in_bytes = (<Circuit as Environment>::BaseField::size_in_bits() + 7) / 8;
+        let mut bytes_le = vec![0u8; size_in_bytes + 1];
+        bytes_le[size_in_bytes] = 1;
+        let _candidate = Field::<Circuit>::from_bytes_le(&bytes_le);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** digits in the given `base`.
+    ///
+    /// This generalizes the reconstruction in `from_bits_le` from powers of two to powers of `base`,
+    /// and is free, as the output is a linear combination of the digits.
+    ///
+    /// The caller must ensure each digit is in `[0, base)`, as this method does not enforce it.
+    /// Furthermore, if `base^digits.len()` exceeds the modulus, the output is reduced modulo the modulus.
+    pub fn from_digits_le(digits: &[Field<E>], base: E::BaseField) -> Self {
+        // Reconstruct the digits as a linear combination representing the original value.
+        // `output` := (base^i * d_i + ... + base^0 * d_0)
+        let mut output = Field::zero();
+        let mut coefficient = E::BaseField::one();
+        for digit in digits {
+            output += Field::from(&digit.linear_combination * coefficient);
+            coefficient *= base;
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    type F = <Circuit as Environment>::BaseField;
+
+    fn check_from_digits_le(mode: Mode, base: u64, values: &[u64]) {
+        for value in values {
+            // Decompose the value into little-endian digits.
+            let mut digits = vec![];
+            let mut remainder = *value;
+            while remainder > 0 {
+                digits.push(remainder % base);
+                remainder /= base;
+            }
+
+            let candidate_digits = digits
+                .iter()
+                .map(|digit| Field::<Circuit>::new(mode, console::Field::from_u64(*digit)))
+                .collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {base} {value}"), || {
+                let candidate = Field::<Circuit>::from_digits_le(&candidate_digits, F::from(base));
+                assert_eq!(console::Field::from_u64(*value), candidate.eject_value());
+                // The reconstruction is a linear combination, and is free.
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_digits_le_base_10() {
+        let values = [0, 1, 9, 10, 42, 1234567890, u64::MAX];
+        check_from_digits_le(Mode::Constant, 10, &values);
+        check_from_digits_le(Mode::Public, 10, &values);
+        check_from_digits_le(Mode::Private, 10, &values);
+    }
+
+    #[test]
+    fn test_from_digits_le_base_256() {
+        let mut rng = TestRng::default();
+        let values = [0, 255, 256, 0xdead_beef, u64::MAX, u64::rand(&mut rng), u64::rand(&mut rng)];
+        check_from_digits_le(Mode::Constant, 256, &values);
+        check_from_digits_le(Mode::Public, 256, &values);
+        check_from_digits_le(Mode::Private, 256, &values);
+
+        // Ensure base-256 digits match the little-endian bytes.
+        let value = u64::rand(&mut rng);
+        let digits = value
+            .to_le_bytes()
+            .iter()
+            .map(|byte| Field::<Circuit>::new(Mode::Private, console::Field::from_u8(*byte)))
+            .collect::<Vec<_>>();
+        let candidate = Field::<Circuit>::from_digits_le(&digits, F::from(256u64));
+        assert_eq!(console::Field::from_u64(value), candidate.eject_value());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_digits_le_base_2_matches_from_bits_le() {
+        let mut rng = TestRng::default();
+        let bits = (0..64).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+        let digits = bits
+            .iter()
+            .map(|bit| Field::<Circuit>::new(Mode::Private, console::Field::from_u8(*bit as u8)))
+            .collect::<Vec<_>>();
+        let booleans = bits.iter().map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit)).collect::<Vec<_>>();
+
+        let expected = Field::<Circuit>::from_bits_le(&booleans);
+        let candidate = Field::<Circuit>::from_digits_le(&digits, F::from(2u64));
+        assert_eq!(expected.eject_value(), candidate.eject_value());
+        Circuit::reset();
+    }
+}
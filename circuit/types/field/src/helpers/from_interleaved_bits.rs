@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bit planes,
+    /// which are interleaved round-robin into a single list of **little-endian** bits.
+    ///
+    /// The `j`-th bit of the `p`-th plane is placed after the `j`-th bit of every plane before `p`,
+    /// and before the `j`-th bit of every plane after `p`, i.e. for two planes `a` and `b`,
+    /// the bits are ordered as `[a_0, b_0, a_1, b_1, ...]`. When the planes are of equal length `n`,
+    /// the `j`-th bit of the `p`-th plane is at index `j * planes.len() + p` of the interleaved bits.
+    /// Once a plane is exhausted, it is skipped, and the remaining planes continue to be interleaved.
+    ///
+    /// The interleaved bits are then reconstructed as in `from_bits_le`.
+    pub fn from_interleaved_bits_le(planes: &[&[Boolean<E>]]) -> Self {
+        // Retrieve the length of the longest plane.
+        let max_len = planes.iter().map(|plane| plane.len()).max().unwrap_or(0);
+        // Interleave the bits of each plane, in order.
+        let bits_le =
+            (0..max_len).flat_map(|j| planes.iter().filter_map(move |plane| plane.get(j))).cloned().collect::<Vec<_>>();
+        // Reconstruct the field element from the interleaved bits.
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_interleaved_bits_le(mode: Mode, plane_lengths: &[usize]) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample the planes.
+            let planes = plane_lengths
+                .iter()
+                .map(|length| (0..*length).map(|_| Boolean::<Circuit>::new(mode, bool::rand(&mut rng))).collect())
+                .collect::<Vec<Vec<_>>>();
+            let plane_slices = planes.iter().map(|plane| plane.as_slice()).collect::<Vec<_>>();
+
+            // Interleave the planes manually.
+            let mut interleaved = Vec::new();
+            for j in 0..plane_lengths.iter().max().copied().unwrap_or(0) {
+                for plane in &planes {
+                    if let Some(bit) = plane.get(j) {
+                        interleaved.push(bit.clone());
+                    }
+                }
+            }
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let expected = Field::<Circuit>::from_bits_le(&interleaved);
+                let candidate = Field::<Circuit>::from_interleaved_bits_le(&plane_slices);
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_interleaved_bits_le_two_planes() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_from_interleaved_bits_le(mode, &[100, 100]);
+            check_from_interleaved_bits_le(mode, &[100, 60]);
+        }
+    }
+
+    #[test]
+    fn test_from_interleaved_bits_le_three_planes() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_from_interleaved_bits_le(mode, &[80, 80, 80]);
+            check_from_interleaved_bits_le(mode, &[80, 0, 40]);
+        }
+    }
+
+    #[test]
+    fn test_from_interleaved_bits_le_order() {
+        // Interleave `a = 0b01` and `b = 0b11` into `[a_0, b_0, a_1, b_1] = [1, 1, 0, 1]`, which is `11`.
+        let a = [Boolean::<Circuit>::constant(true), Boolean::constant(false)];
+        let b = [Boolean::<Circuit>::constant(true), Boolean::constant(true)];
+        let candidate = Field::<Circuit>::from_interleaved_bits_le(&[&a, &b]);
+        assert_eq!(console::Field::from_u8(11), candidate.eject_value());
+
+        // Ensure no planes reconstructs to zero.
+        assert_eq!(console::Field::zero(), Field::<Circuit>::from_interleaved_bits_le(&[]).eject_value());
+    }
+}
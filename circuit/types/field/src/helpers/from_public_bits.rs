@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new **public** base field element from a list of **little-endian** bits,
+    /// where the bits themselves are allocated as private variables.
+    ///
+    /// Calling `from_bits_le` on public bits exposes one public input per bit. Instead, this method exposes
+    /// the reconstructed value as a single public input, and witnesses its bits privately. The private bits
+    /// are enforced to reconstruct the public value, and to be less than `BaseField::MODULUS`, so they are
+    /// the canonical bits of the value, and are returned by subsequent calls to `to_bits_le`.
+    ///
+    /// Note: If the bits exceed `E::BaseField::size_in_bits()` with nonzero excess bits,
+    /// or represent a value that is greater than or equal to `BaseField::MODULUS`, this method will halt.
+    #[cfg(console)]
+    pub fn from_public_bits_le(bits_le: &[bool]) -> Self {
+        // Reconstruct the field value from the bits.
+        let value = match console::Field::<E::Network>::from_bits_le(bits_le) {
+            Ok(value) => value,
+            Err(error) => E::halt(format!("Failed to reconstruct a field element from public bits: {error}")),
+        };
+
+        // Allocate the field value as a single public variable.
+        let output = Field::new(Mode::Public, value);
+
+        // Witness the private bits, and ensure they reconstruct the public field value.
+        let bits_le = output.to_bits_le();
+        // Ensure the private bits are less than `BaseField::MODULUS`.
+        Self::is_less_than_modulus(&bits_le).enforce_true();
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_public_bits_le() {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = expected.to_bits_le();
+
+            Circuit::scope(format!("Public {i}"), || {
+                let candidate = Field::<Circuit>::from_public_bits_le(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(Mode::Public, candidate.eject_mode());
+                // The public field element is decomposed into 253 private bits (as in `to_bits_le`),
+                // which are range checked with 252 private variables and 253 constraints.
+                assert_scope!(0, 1, 505, 507);
+
+                // Ensure the private bits are cached, and match the given bits.
+                let candidate_bits = candidate.to_bits_le();
+                assert_eq!(given_bits, candidate_bits.eject_value());
+                assert!(candidate_bits.iter().all(|bit| bit.is_private()));
+                assert_scope!(0, 1, 505, 507);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_public_bits_le_reduces_public_inputs() {
+        let mut rng = TestRng::default();
+
+        let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+        let given_bits = expected.to_bits_le();
+
+        // Reconstruct the field element from public bits.
+        let bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(Mode::Public, *bit)).collect::<Vec<_>>();
+        let candidate = Field::<Circuit>::from_bits_le(&bits);
+        assert_eq!(expected, candidate.eject_value());
+        let num_public_bits = Circuit::num_public();
+        Circuit::reset();
+
+        // Reconstruct the field element as a single public input.
+        let candidate = Field::<Circuit>::from_public_bits_le(&given_bits);
+        assert_eq!(expected, candidate.eject_value());
+        let num_public_field = Circuit::num_public();
+        Circuit::reset();
+
+        // Ensure the per-bit public inputs are replaced by a single public input.
+        assert_eq!(num_public_bits, num_public_field + given_bits.len() as u64 - 1);
+    }
+
+    #[test]
+    fn test_from_public_bits_le_excess_zero_bits() {
+        let mut rng = TestRng::default();
+
+        // Sample a random element, with excess zero bits.
+        let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+        let given_bits = [expected.to_bits_le(), vec![false; 8]].concat();
+
+        let candidate = Field::<Circuit>::from_public_bits_le(&given_bits);
+        assert_eq!(expected, candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_public_bits_le_excess_nonzero_bits() {
+        // Ensure a value beyond the field size is rejected.
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let mut given_bits = vec![false; size_in_bits];
+        given_bits.push(true);
+        let _ = Field::<Circuit>::from_public_bits_le(&given_bits);
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new constant base field element from the positions of its set bits, in **little-endian** order,
+    /// i.e. `2^positions[0] + ... + 2^positions[n-1]`.
+    ///
+    /// This is equivalent to `from_bits_le` on the constant bits that are set at the given positions,
+    /// without constructing a boolean for every bit. Halts if the positions are not strictly increasing,
+    /// if a position is not less than `E::BaseField::size_in_bits()`, or if the value is not less than
+    /// `BaseField::MODULUS`.
+    pub fn from_set_positions_le(positions: &[usize]) -> Self {
+        // Ensure the positions are strictly increasing.
+        if positions.windows(2).any(|pair| pair[0] >= pair[1]) {
+            E::halt("The positions of the set bits must be sorted and unique")
+        }
+        // Ensure the positions are within the base field size.
+        let size_in_bits = E::BaseField::size_in_bits();
+        if let Some(position) = positions.iter().find(|position| **position >= size_in_bits) {
+            E::halt(format!("The position of a set bit ({position}) must be less than {size_in_bits}"))
+        }
+
+        // Pack the set bits into little-endian bytes.
+        let mut bytes = vec![0u8; (size_in_bits + 7) / 8];
+        for position in positions {
+            bytes[position / 8] |= 1 << (position % 8);
+        }
+
+        // Ensure the value is unchanged when reduced modulo `BaseField::MODULUS`.
+        let value = E::BaseField::from_bytes_le_mod_order(&bytes);
+        let value_bits_le = value.to_bits_le();
+        let num_set_bits = value_bits_le.iter().filter(|bit| **bit).count();
+        if num_set_bits != positions.len() || positions.iter().any(|position| !value_bits_le[*position]) {
+            let hex = bytes.iter().rev().map(|byte| format!("{byte:02x}")).collect::<String>();
+            E::halt(format!("constant field bits exceed modulus: 0x{hex}"))
+        }
+
+        Field::from(E::one() * value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_set_positions_le() {
+        let mut rng = TestRng::default();
+
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for i in 0..ITERATIONS {
+            // Sample sparse positions within the data size, or the set positions of a random field element.
+            let mut positions = (0..size_in_data_bits).filter(|_| u8::rand(&mut rng) % 16 == 0).collect::<Vec<usize>>();
+            if i % 2 == 0 {
+                let value: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                positions = value.to_bits_le().iter().enumerate().filter(|(_, bit)| **bit).map(|(j, _)| j).collect();
+            }
+
+            // Expand the positions into the dense bits.
+            let mut given_bits = vec![false; size_in_bits];
+            for position in &positions {
+                given_bits[*position] = true;
+            }
+            let given_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::constant(*bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{i}"), || {
+                let candidate = Field::<Circuit>::from_set_positions_le(&positions);
+                assert_eq!(Field::<Circuit>::from_bits_le(&given_bits).eject_value(), candidate.eject_value());
+                assert!(candidate.is_constant());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+
+        // Ensure no positions reconstruct to zero.
+        assert_eq!(console::Field::zero(), Field::<Circuit>::from_set_positions_le(&[]).eject_value());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be less than")]
+    fn test_from_set_positions_le_out_of_range_fails() {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let _candidate = Field::<Circuit>::from_set_positions_le(&[0, size_in_bits]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be sorted and unique")]
+    fn test_from_set_positions_le_unsorted_fails() {
+        let _candidate = Field::<Circuit>::from_set_positions_le(&[3, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be sorted and unique")]
+    fn test_from_set_positions_le_duplicate_fails() {
+        let _candidate = Field::<Circuit>::from_set_positions_le(&[1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "constant field bits exceed modulus")]
+    fn test_from_set_positions_le_exceeds_modulus_fails() {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let _candidate = Field::<Circuit>::from_set_positions_le(&(0..size_in_bits).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a sparse list of **little-endian** bits, as in `from_bits_le`,
+    /// where an absent bit (`None`) is treated as a constant `false`, and thus does not incur any cost.
+    pub fn from_sparse_bits_le(bits_le: &[Option<Boolean<E>>]) -> Self {
+        let bits_le =
+            bits_le.iter().map(|bit| bit.clone().unwrap_or_else(|| Boolean::constant(false))).collect::<Vec<_>>();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_sparse_bits_le(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element, and drop a random subset of its `false` bits.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = expected.to_bits_le();
+            let sparse_bits = given_bits
+                .iter()
+                .map(|bit| match *bit || bool::rand(&mut rng) {
+                    true => Some(Boolean::<Circuit>::new(mode, *bit)),
+                    false => None,
+                })
+                .collect::<Vec<_>>();
+            // Materialize the sparse bits, with each absent bit as a constant `false`.
+            let materialized_bits = sparse_bits
+                .iter()
+                .map(|bit| bit.clone().unwrap_or_else(|| Boolean::constant(false)))
+                .collect::<Vec<_>>();
+
+            let materialized_count = Circuit::scope(format!("Materialized {mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le(&materialized_bits);
+                assert_eq!(expected, candidate.eject_value());
+                (
+                    Circuit::num_constants_in_scope(),
+                    Circuit::num_private_in_scope(),
+                    Circuit::num_constraints_in_scope(),
+                )
+            });
+            let sparse_count = Circuit::scope(format!("Sparse {mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_sparse_bits_le(&sparse_bits);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(size_in_bits, candidate.bits_le.get().unwrap().len());
+                (
+                    Circuit::num_constants_in_scope(),
+                    Circuit::num_private_in_scope(),
+                    Circuit::num_constraints_in_scope(),
+                )
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure the sparse and materialized bits incur the same cost.
+            assert_eq!(materialized_count, sparse_count);
+        }
+
+        // Ensure a list of absent bits is zero, and is free.
+        let candidate = Field::<Circuit>::from_sparse_bits_le(&vec![None; size_in_bits]);
+        assert_eq!(console::Field::zero(), candidate.eject_value());
+        assert_eq!(0, Circuit::num_constraints());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_sparse_bits_le() {
+        check_from_sparse_bits_le(Mode::Constant);
+        check_from_sparse_bits_le(Mode::Public);
+        check_from_sparse_bits_le(Mode::Private);
+    }
+}
@@ -16,10 +16,60 @@
 
 use super::*;
 
+pub use bits_le_equal_to::BitsLEEqualTo;
+pub use error_policy::{AbortPolicy, ErrorPolicy, HaltPolicy};
+pub use from_bits_as::FromBitsAs;
+pub use from_bits_nonzero::FromBitsNonzero;
+pub use from_bits_saturating::FromBitsSaturating;
+pub use range_witness::RangeWitness;
+
+pub mod add_bits_into;
+pub mod bits_le_equal_to;
+pub mod commit_bits;
+pub mod enforce_bits;
+pub mod error_policy;
 pub mod from_bits;
+pub mod from_bits_as;
+pub mod from_bits_assume_excess_zero;
+pub mod from_bits_checked_reduction;
+pub mod from_bits_constants;
+pub mod from_bits_ct;
+pub mod from_bits_endianness;
+pub mod from_bits_iter;
+pub mod from_bits_labeled;
+pub mod from_bits_nonzero;
+pub mod from_bits_pair;
+pub mod from_bits_params;
+pub mod from_bits_policy;
+pub mod from_bits_reporting;
+pub mod from_bits_saturating;
+pub mod from_bits_trace;
+pub mod from_bits_verified;
+pub mod from_bits_weight_bounded;
+pub mod from_bits_with_carry;
+pub mod from_bits_with_parity;
 pub mod from_boolean;
+pub mod from_bytes;
+pub mod from_digits;
+pub mod from_interleaved_bits;
+pub mod from_public_bits;
+pub mod from_set_positions;
+pub mod from_sparse_bits;
 pub mod one;
+pub mod pack_with_length;
+pub mod parity;
+pub mod range_witness;
 pub mod to_bits;
 pub mod to_lower_bits;
 pub mod to_upper_bits;
+pub mod unpack_fields;
 pub mod zero;
+
+/// The order in which a list of bits is given.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// The least significant bit is given first.
+    Little,
+    /// The most significant bit is given first.
+    Big,
+}
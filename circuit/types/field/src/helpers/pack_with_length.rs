@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Packs a list of **little-endian** bits into base field elements, prefixed by the number of bits.
+    ///
+    /// The first field element is the constant `bits_le.len()`, followed by the bits in chunks of
+    /// `E::BaseField::size_in_data_bits()`, where the last chunk may be shorter. As the length is included,
+    /// inputs of different lengths never pack to the same field elements, even if their chunks reconstruct
+    /// to the same values (e.g. when one input is the other with trailing zero bits).
+    ///
+    /// As each chunk fits within `size_in_data_bits`, no range check is required, and the packing is free.
+    pub fn pack_with_length_le(bits_le: &[Boolean<E>]) -> Vec<Self> {
+        // Prepend the number of bits, as a constant.
+        let length = Field::from(E::one() * E::BaseField::from(bits_le.len() as u128));
+        // Reconstruct each chunk of bits.
+        std::iter::once(length)
+            .chain(bits_le.chunks(E::BaseField::size_in_data_bits()).map(Self::from_bits_le))
+            .collect()
+    }
+
+    /// Unpacks a list of base field elements, as produced by `pack_with_length_le`, into **little-endian** bits.
+    ///
+    /// Each chunk is enforced to fit within its number of bits, which costs one private variable
+    /// and one constraint per bit, plus one constraint per chunk.
+    ///
+    /// Returns an error if the length is missing or not a constant, or if the number of field elements
+    /// does not match the length.
+    pub fn unpack_with_length_le(fields: &[Self]) -> Result<Vec<Boolean<E>>> {
+        // Retrieve the data size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+
+        // Retrieve the length, which must be a constant.
+        let (length, chunks) = match fields.split_first() {
+            Some((length, chunks)) => (length, chunks),
+            None => bail!("The packed field elements are missing the length"),
+        };
+        ensure!(length.is_constant(), "The length of the packed field elements must be a constant");
+
+        // Convert the length into a `usize`.
+        let length_bits = length.linear_combination.value().to_bits_le();
+        ensure!(length_bits.iter().skip(64).all(|bit| !bit), "The length of the packed field elements is too large");
+        let length = length_bits.iter().take(64).rev().fold(0u64, |length, bit| (length << 1) | (*bit as u64));
+        let length = match usize::try_from(length) {
+            Ok(length) => length,
+            Err(_) => bail!("The length of the packed field elements is too large"),
+        };
+
+        // Ensure the number of chunks matches the length.
+        let num_chunks = (length + size_in_data_bits - 1) / size_in_data_bits;
+        ensure!(
+            chunks.len() == num_chunks,
+            "Expected {num_chunks} packed field elements for {length} bits, found {}",
+            chunks.len()
+        );
+
+        // Unpack each chunk into its bits.
+        Ok(chunks
+            .iter()
+            .enumerate()
+            .flat_map(|(i, chunk)| chunk.to_lower_bits_le(size_in_data_bits.min(length - i * size_in_data_bits)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_pack_with_length_le(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+
+        for num_bits in [0, 1, 8, size_in_data_bits, size_in_data_bits + 1, 3 * size_in_data_bits - 1] {
+            let given_bits = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let candidate_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {num_bits}"), || {
+                let packed = Field::<Circuit>::pack_with_length_le(&candidate_bits);
+                assert_eq!(1 + (num_bits + size_in_data_bits - 1) / size_in_data_bits, packed.len());
+                assert_eq!(console::Field::from_u64(num_bits as u64), packed[0].eject_value());
+                // As each chunk fits within `size_in_data_bits`, the packing is free.
+                assert_scope!(0, 0, 0, 0);
+
+                // Ensure the bits round trip.
+                let unpacked = Field::<Circuit>::unpack_with_length_le(&packed).unwrap();
+                assert_eq!(given_bits, unpacked.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_pack_with_length_le_constant() {
+        check_pack_with_length_le(Mode::Constant);
+    }
+
+    #[test]
+    fn test_pack_with_length_le_public() {
+        check_pack_with_length_le(Mode::Public);
+    }
+
+    #[test]
+    fn test_pack_with_length_le_private() {
+        check_pack_with_length_le(Mode::Private);
+    }
+
+    #[test]
+    fn test_pack_with_length_le_distinct_lengths() {
+        let mut rng = TestRng::default();
+
+        // Ensure inputs of different lengths, including zero-padded inputs with identical chunks, pack differently.
+        let given_bits = (0..300).map(|_| Boolean::<Circuit>::constant(bool::rand(&mut rng))).collect::<Vec<_>>();
+        let padded_bits = [given_bits.clone(), vec![Boolean::constant(false); 4]].concat();
+        let mut packings = vec![Field::<Circuit>::pack_with_length_le(&padded_bits).eject_value()];
+        for num_bits in 0..=given_bits.len() {
+            packings.push(Field::<Circuit>::pack_with_length_le(&given_bits[..num_bits]).eject_value());
+        }
+        for (i, first) in packings.iter().enumerate() {
+            for second in packings.iter().skip(i + 1) {
+                assert_ne!(first, second);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unpack_with_length_le_invalid() {
+        let bits = vec![Boolean::<Circuit>::new(Mode::Private, true); 300];
+        let packed = Field::<Circuit>::pack_with_length_le(&bits);
+
+        // Ensure a missing length is rejected.
+        assert!(Field::<Circuit>::unpack_with_length_le(&[]).is_err());
+        // Ensure a missing or an extra chunk is rejected.
+        assert!(Field::<Circuit>::unpack_with_length_le(&packed[..packed.len() - 1]).is_err());
+        assert!(Field::<Circuit>::unpack_with_length_le(&[packed.clone(), vec![Field::zero()]].concat()).is_err());
+        // Ensure a non-constant length is rejected.
+        let length = Field::<Circuit>::new(Mode::Private, packed[0].eject_value());
+        assert!(Field::<Circuit>::unpack_with_length_le(&[vec![length], packed[1..].to_vec()].concat()).is_err());
+        Circuit::reset();
+    }
+}
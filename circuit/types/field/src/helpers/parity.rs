@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Returns the parity of the field element, defined as the least-significant bit
+    /// of the canonical little-endian bit representation of the field element.
+    ///
+    /// If the cached bits of the field element are canonical (e.g. they were cached by `from_bits_le`,
+    /// which range checks them), this method incurs no additional cost. Otherwise, the field element is
+    /// decomposed into bits (or the bits cached by `to_bits_le` are reused), and the bits are enforced to be
+    /// less than `BaseField::MODULUS`, after which they are recorded as canonical for subsequent calls.
+    /// Without the range check, a prover could witness the bits of `self + BaseField::MODULUS`,
+    /// which flips the least-significant bit, as the modulus is odd.
+    ///
+    /// Note: A single constraint of the form `self = 2 * k + b` is *not* sufficient to
+    /// bind `b` to the parity, as any field element is divisible by two in a prime field.
+    /// Thus, the full bit decomposition is required when the bits are not cached.
+    pub fn parity(&self) -> Boolean<E> {
+        match self.bits_le.get() {
+            // If the cached bits are canonical, return the least-significant bit at no additional cost.
+            Some(bits_le) if self.are_bits_le_canonical.get().is_some() => bits_le[0].clone(),
+            // Otherwise, decompose the field element into bits, which caches the bits, and range check them.
+            _ => {
+                let bits_le = self.to_bits_le();
+                // Ensure the bits are less than `BaseField::MODULUS`.
+                Self::is_less_than_modulus(&bits_le).enforce_true();
+                // Record that the cached bits are canonical.
+                let _ = self.are_bits_le_canonical.set(());
+                bits_le[0].clone()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_parity_from_bits(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+            let candidate = Field::<Circuit>::from_bits_le(&given_bits);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let parity = candidate.parity();
+                assert_eq!(expected.to_bits_le()[0], parity.eject_value());
+                assert_eq!(given_bits[0].eject_value(), parity.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_parity_from_arithmetic(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample random elements.
+            let first: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let second: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let expected = (first + second).to_bits_le()[0];
+
+            let a = Field::<Circuit>::new(mode, first);
+            let b = Field::<Circuit>::new(mode, second);
+            let candidate = a + b;
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let parity = candidate.parity();
+                assert_eq!(expected, parity.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                // Ensure a subsequent call to `parity` does not incur additional costs.
+                let parity = candidate.parity();
+                assert_eq!(expected, parity.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_parity_after_to_bits_le(mode: Mode, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element, and cache its bits with `to_bits_le`, which does not range check them.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let candidate = Field::<Circuit>::new(mode, expected);
+            let given_bits = candidate.to_bits_le();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                // Ensure the cached bits are reused, and range checked.
+                let parity = candidate.parity();
+                assert_eq!(expected.to_bits_le()[0], parity.eject_value());
+                assert_eq!(given_bits[0].eject_value(), parity.eject_value());
+                assert_scope!(0, 0, num_private, num_constraints);
+
+                // Ensure a subsequent call to `parity` does not range check the bits again.
+                let parity = candidate.parity();
+                assert_eq!(expected.to_bits_le()[0], parity.eject_value());
+                assert_scope!(0, 0, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_parity_after_to_bits_le_constant() {
+        check_parity_after_to_bits_le(Mode::Constant, 0, 0);
+    }
+
+    #[test]
+    fn test_parity_after_to_bits_le_public() {
+        check_parity_after_to_bits_le(Mode::Public, 252, 253);
+    }
+
+    #[test]
+    fn test_parity_after_to_bits_le_private() {
+        check_parity_after_to_bits_le(Mode::Private, 252, 253);
+    }
+
+    #[test]
+    fn test_parity_rejects_non_canonical_bits() {
+        let mut rng = TestRng::default();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        // Compute the bits of `BaseField::MODULUS`, as `(BaseField::MODULUS - 1) + 1`.
+        let mut modulus = (-<Circuit as Environment>::BaseField::one()).to_bits_le();
+        modulus.truncate(size_in_bits);
+        assert!(!modulus[0]);
+        modulus[0] = true;
+
+        for i in 0..ITERATIONS {
+            // Sample a small value, so that `value + BaseField::MODULUS` fits within `size_in_bits`.
+            let value = u64::rand(&mut rng);
+            let expected = console::Field::<<Circuit as Environment>::Network>::from_u64(value);
+
+            // Compute the bits of `value + BaseField::MODULUS`.
+            let mut carry = false;
+            let mut bits = Vec::with_capacity(size_in_bits);
+            for (j, modulus_bit) in modulus.iter().enumerate() {
+                let value_bit = j < 64 && (value >> j) & 1 == 1;
+                bits.push(value_bit ^ *modulus_bit ^ carry);
+                carry = (value_bit && *modulus_bit) || (carry && (value_bit ^ *modulus_bit));
+            }
+            assert!(!carry);
+            // Ensure the least-significant bit is flipped.
+            assert_ne!(expected.to_bits_le()[0], bits[0]);
+
+            Circuit::scope(format!("{i}"), || {
+                // Witness the non-canonical bits, enforce they reconstruct the field element, and cache them,
+                // as a malicious prover could in `to_bits_le`.
+                let candidate = Field::<Circuit>::new(Mode::Private, expected);
+                let bits_le = bits.iter().map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit)).collect::<Vec<_>>();
+                let mut reconstructed = Field::zero();
+                let mut coefficient = Field::one();
+                for bit in &bits_le {
+                    reconstructed += Field::from_boolean(bit) * &coefficient;
+                    coefficient = coefficient.double();
+                }
+                Circuit::assert_eq(&candidate, &reconstructed);
+                assert!(candidate.bits_le.set(bits_le).is_ok());
+                assert!(Circuit::is_satisfied_in_scope());
+
+                // Ensure the range check in `parity` rejects the non-canonical cached bits.
+                let parity = candidate.parity();
+                assert_eq!(bits[0], parity.eject_value());
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_parity_from_bits_constant() {
+        check_parity_from_bits(Mode::Constant);
+    }
+
+    #[test]
+    fn test_parity_from_bits_public() {
+        check_parity_from_bits(Mode::Public);
+    }
+
+    #[test]
+    fn test_parity_from_bits_private() {
+        check_parity_from_bits(Mode::Private);
+    }
+
+    #[test]
+    fn test_parity_from_arithmetic_constant() {
+        check_parity_from_arithmetic(Mode::Constant, 253, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_parity_from_arithmetic_public() {
+        check_parity_from_arithmetic(Mode::Public, 0, 0, 505, 507);
+    }
+
+    #[test]
+    fn test_parity_from_arithmetic_private() {
+        check_parity_from_arithmetic(Mode::Private, 0, 0, 505, 507);
+    }
+}
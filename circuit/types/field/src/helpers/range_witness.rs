@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The intermediate witnesses of the range check in `Field::from_bits_le_with_range_witness`,
+/// which ensures `E::BaseField::size_in_bits()` **little-endian** bits are less than `BaseField::MODULUS`.
+///
+/// The range check compares the bits against `BaseField::MODULUS - 1`, from the least significant bit
+/// to the most significant bit. Thus, the witness has one boolean per bit, where the `i`-th boolean is
+/// `true` if and only if the lowest `i + 1` bits are greater than the lowest `i + 1` bits of `BaseField::MODULUS - 1`.
+/// The bits are less than `BaseField::MODULUS` if and only if the last boolean is `false`.
+///
+/// If the range check is skipped (i.e. the bits fit within `E::BaseField::size_in_data_bits()`),
+/// the witness is empty.
+#[derive(Clone)]
+pub struct RangeWitness<E: Environment> {
+    /// The intermediate results of the comparison, for each prefix of the bits.
+    is_greater: Vec<Boolean<E>>,
+}
+
+impl<E: Environment> Default for RangeWitness<E> {
+    /// Returns an empty range witness.
+    fn default() -> Self {
+        Self { is_greater: Vec::new() }
+    }
+}
+
+impl<E: Environment> RangeWitness<E> {
+    /// Computes the range witness for the given `E::BaseField::size_in_bits()` **little-endian** bits.
+    ///
+    /// This method costs at most one private variable and one constraint per non-constant bit.
+    pub fn new(bits_le: &[Boolean<E>]) -> Self {
+        // Retrieve the modulus & subtract by 1 as we'll check `bits_le` is less than or *equal* to this value.
+        // (For advanced users) BaseField::MODULUS - 1 is equivalent to -1 in the field.
+        let modulus_minus_one = -E::BaseField::one();
+
+        // Compute `(BaseField::MODULUS - 1) < bits_le` for each prefix of the bits.
+        let mut rest_is_greater = Boolean::constant(false);
+        let is_greater = modulus_minus_one
+            .to_bits_le()
+            .iter()
+            .zip_eq(bits_le)
+            .map(|(this, that)| {
+                rest_is_greater = if *this { that.bitand(&rest_is_greater) } else { that.bitor(&rest_is_greater) };
+                rest_is_greater.clone()
+            })
+            .collect();
+
+        Self { is_greater }
+    }
+
+    /// Returns the intermediate results of the comparison, for each prefix of the bits.
+    pub fn is_greater(&self) -> &[Boolean<E>] {
+        &self.is_greater
+    }
+
+    /// Returns `true` if the range check was skipped.
+    pub fn is_empty(&self) -> bool {
+        self.is_greater.is_empty()
+    }
+
+    /// Returns `true` if the bits are less than `BaseField::MODULUS`, which is `!((BaseField::MODULUS - 1) < bits_le)`.
+    ///
+    /// Note: If the range check was skipped, this returns constant `true`.
+    pub fn is_less_than_modulus(&self) -> Boolean<E> {
+        match self.is_greater.last() {
+            Some(is_greater) => !is_greater,
+            None => Boolean::constant(true),
+        }
+    }
+
+    /// Returns `true` if the witness is consistent with the given **little-endian** bits,
+    /// and confirms they represent a value that is less than `BaseField::MODULUS`.
+    pub fn verify(&self, bits_le: &[bool]) -> bool {
+        // Retrieve the data and base field size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        // Ensure the excess bits are zero.
+        if bits_le.iter().skip(size_in_bits).any(|bit| *bit) {
+            return false;
+        }
+        let bits_le = &bits_le[..bits_le.len().min(size_in_bits)];
+
+        // If the range check was skipped, ensure the bits fit within `size_in_data_bits`.
+        if self.is_empty() {
+            return bits_le.iter().skip(size_in_data_bits).all(|bit| !bit);
+        }
+
+        // Ensure there is one intermediate result per bit.
+        if bits_le.len() != size_in_bits || self.is_greater.len() != size_in_bits {
+            return false;
+        }
+
+        // Recompute each intermediate result, and ensure it matches the witness.
+        let mut rest_is_greater = false;
+        for ((this, that), is_greater) in (-E::BaseField::one()).to_bits_le().iter().zip(bits_le).zip(&self.is_greater)
+        {
+            rest_is_greater = if *this { *that && rest_is_greater } else { *that || rest_is_greater };
+            if is_greater.eject_value() != rest_is_greater {
+                return false;
+            }
+        }
+
+        // Ensure the bits are not greater than `BaseField::MODULUS - 1`.
+        !rest_is_greater
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_range_witness(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = expected.to_bits_le();
+            let candidate_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let (candidate, range_witness) = Field::<Circuit>::from_bits_le_with_range_witness(&candidate_bits);
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure the range witness has one intermediate result per bit, and confirms in-field membership.
+                assert_eq!(size_in_bits, range_witness.is_greater().len());
+                assert!(range_witness.is_less_than_modulus().eject_value());
+                assert!(range_witness.verify(&given_bits));
+                // Ensure the range witness is not consistent with different bits.
+                let mut other_bits = given_bits.clone();
+                other_bits[0] = !other_bits[0];
+                assert!(!range_witness.verify(&other_bits));
+                // Ensure the range witness does not change the costs of `from_bits_le`.
+                match mode.is_constant() {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, 252, 253),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_range_witness_constant() {
+        check_range_witness(Mode::Constant);
+    }
+
+    #[test]
+    fn test_range_witness_public() {
+        check_range_witness(Mode::Public);
+    }
+
+    #[test]
+    fn test_range_witness_private() {
+        check_range_witness(Mode::Private);
+    }
+
+    #[test]
+    fn test_range_witness_empty() {
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+
+        // Ensure the range check is skipped for bits that fit within `size_in_data_bits`.
+        let given_bits = vec![true; size_in_data_bits];
+        let candidate_bits =
+            given_bits.iter().map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit)).collect::<Vec<_>>();
+        let (_, range_witness) = Field::<Circuit>::from_bits_le_with_range_witness(&candidate_bits);
+        assert!(range_witness.is_empty());
+        assert!(range_witness.is_less_than_modulus().eject_value());
+        assert!(range_witness.verify(&given_bits));
+        assert!(!range_witness.verify(&[given_bits, vec![true]].concat()));
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_range_witness_out_of_range() {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        // Ensure the range witness rejects bits that are greater than or equal to `BaseField::MODULUS`.
+        let given_bits = vec![true; size_in_bits];
+        let candidate_bits =
+            given_bits.iter().map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit)).collect::<Vec<_>>();
+        let range_witness = RangeWitness::new(&candidate_bits);
+        assert!(!range_witness.is_less_than_modulus().eject_value());
+        assert!(!range_witness.verify(&given_bits));
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Initializes `field_count` base field elements from a packed list of **little-endian** bits,
+    /// where each field element is packed into `E::BaseField::size_in_data_bits()` consecutive bits.
+    ///
+    /// As each field element fits within `size_in_data_bits`, no range check is required,
+    /// and the reconstruction of each field element is free.
+    ///
+    /// Returns an error if `bits_le` does not contain exactly `field_count * size_in_data_bits` bits.
+    pub fn unpack_fields_le(bits_le: &[Boolean<E>], field_count: usize) -> Result<Vec<Self>> {
+        // Retrieve the data size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+
+        // Ensure the number of bits matches the number of field elements.
+        let expected_num_bits = match field_count.checked_mul(size_in_data_bits) {
+            Some(expected_num_bits) => expected_num_bits,
+            None => bail!("The number of field elements ({field_count}) is too large"),
+        };
+        ensure!(
+            bits_le.len() == expected_num_bits,
+            "Expected {expected_num_bits} bits for {field_count} field elements, found {} bits",
+            bits_le.len()
+        );
+
+        // Reconstruct each field element.
+        Ok(bits_le.chunks(size_in_data_bits).map(Self::from_bits_le).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_unpack_fields_le(mode: Mode) -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+
+        for field_count in 0..ITERATIONS as usize {
+            // Sample the packed bits of `field_count` random field elements.
+            let given_bits: Vec<bool> = (0..field_count * size_in_data_bits).map(|_| Uniform::rand(&mut rng)).collect();
+            let expected = given_bits
+                .chunks(size_in_data_bits)
+                .map(console::Field::<<Circuit as Environment>::Network>::from_bits_le)
+                .collect::<Result<Vec<_>>>()?;
+            let candidate_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {field_count}"), || {
+                let candidate = Field::<Circuit>::unpack_fields_le(&candidate_bits, field_count).unwrap();
+                assert_eq!(expected, candidate.eject_value());
+                // As each field element fits within `size_in_data_bits`, the reconstruction is free.
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_fields_le_constant() -> Result<()> {
+        check_unpack_fields_le(Mode::Constant)
+    }
+
+    #[test]
+    fn test_unpack_fields_le_public() -> Result<()> {
+        check_unpack_fields_le(Mode::Public)
+    }
+
+    #[test]
+    fn test_unpack_fields_le_private() -> Result<()> {
+        check_unpack_fields_le(Mode::Private)
+    }
+
+    #[test]
+    fn test_unpack_fields_le_length_mismatch() {
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let given_bits = vec![Boolean::<Circuit>::new(Mode::Private, true); 3 * size_in_data_bits];
+
+        // Ensure a stream that is too short is rejected.
+        assert!(Field::<Circuit>::unpack_fields_le(&given_bits[1..], 3).is_err());
+        // Ensure a stream that is too long is rejected.
+        assert!(Field::<Circuit>::unpack_fields_le(&given_bits, 2).is_err());
+        assert!(Field::<Circuit>::unpack_fields_le(&given_bits[..1], 0).is_err());
+        // Ensure an overflowing number of field elements is rejected.
+        assert!(Field::<Circuit>::unpack_fields_le(&given_bits, usize::MAX).is_err());
+        // Ensure the exact stream is accepted.
+        assert_eq!(3, Field::<Circuit>::unpack_fields_le(&given_bits, 3).unwrap().len());
+        Circuit::reset();
+    }
+}
@@ -19,6 +19,17 @@
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
 mod helpers;
+pub use helpers::{
+    AbortPolicy,
+    BitsLEEqualTo,
+    Endianness,
+    ErrorPolicy,
+    FromBitsAs,
+    FromBitsNonzero,
+    FromBitsSaturating,
+    HaltPolicy,
+    RangeWitness,
+};
 
 pub mod add;
 pub mod compare;
@@ -38,7 +49,14 @@ pub mod ternary;
 #[cfg(test)]
 use console::{TestRng, Uniform};
 #[cfg(test)]
-use snarkvm_circuit_environment::{assert_count, assert_output_mode, assert_scope, count, output_mode};
+use snarkvm_circuit_environment::{
+    assert_count,
+    assert_count_fails,
+    assert_output_mode,
+    assert_scope,
+    count,
+    output_mode,
+};
 
 use snarkvm_circuit_environment::prelude::*;
 use snarkvm_circuit_types_boolean::Boolean;
@@ -50,6 +68,9 @@ pub struct Field<E: Environment> {
     /// An optional secondary representation in little-endian bits is provided,
     /// so that calls to `ToBits` only incur constraint costs once.
     bits_le: OnceCell<Vec<Boolean<E>>>,
+    /// Set once the cached bits are enforced to be less than `BaseField::MODULUS`,
+    /// i.e. once they are the canonical bit representation of the field.
+    are_bits_le_canonical: OnceCell<()>,
 }
 
 impl<E: Environment> FieldTrait for Field<E> {}
@@ -60,7 +81,11 @@ impl<E: Environment> Inject for Field<E> {
 
     /// Initializes a field circuit from a console field.
     fn new(mode: Mode, field: Self::Primitive) -> Self {
-        Self { linear_combination: E::new_variable(mode, *field).into(), bits_le: Default::default() }
+        Self {
+            linear_combination: E::new_variable(mode, *field).into(),
+            bits_le: Default::default(),
+            are_bits_le_canonical: Default::default(),
+        }
     }
 }
 
@@ -140,7 +165,7 @@ impl<E: Environment> Display for Field<E> {
 
 impl<E: Environment> From<LinearCombination<E::BaseField>> for Field<E> {
     fn from(linear_combination: LinearCombination<E::BaseField>) -> Self {
-        Self { linear_combination, bits_le: Default::default() }
+        Self { linear_combination, bits_le: Default::default(), are_bits_le_canonical: Default::default() }
     }
 }
 
@@ -19,6 +19,7 @@ use super::*;
 pub mod from_bits;
 pub mod from_x_coordinate;
 pub mod from_xy_coordinates;
+pub mod mul_by_bits;
 pub mod mul_by_cofactor;
 pub mod to_bits;
 pub mod to_x_coordinate;
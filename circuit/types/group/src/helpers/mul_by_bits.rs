@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuit_environment::Measurement;
+
+impl<E: Environment> Group<E> {
+    /// Returns the product of the group element and a scalar given as a list of **little-endian** bits.
+    ///
+    /// This is `self * bits_be` on the reversed bits, and avoids the range check in
+    /// `self * Scalar::from_bits_le(bits_le)`. As the group element is in the prime-order subgroup,
+    /// the product is equivalent to the product with the integer represented by `bits_le`,
+    /// reduced modulo the scalar field modulus.
+    pub fn mul_by_bits_le(&self, bits_le: &[Boolean<E>]) -> Group<E> {
+        let bits_be = bits_le.iter().rev().cloned().collect::<Vec<_>>();
+        self * bits_be.as_slice()
+    }
+}
+
+/// The operation of `Group::mul_by_bits_le`, which keys its `Metrics` and `OutputMode`.
+pub trait MulByBitsLE {}
+
+impl<E: Environment> Metrics<dyn MulByBitsLE> for Group<E> {
+    /// The mode of the group element, the mode of the bits, and the number of bits.
+    /// If the bits are constant, the number of bits excludes the most-significant zero bits,
+    /// as the double-and-add is free until the most-significant set bit.
+    type Case = (Mode, Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        // Each iteration after the first costs a double, an addition with the group element, and a ternary.
+        // The number of constants depends on the intermediate values, so only an upper bound is given.
+        let (num_private, num_constraints) = match case {
+            (_, _, 0) | (Mode::Constant, Mode::Constant, _) => (0, 0),
+            // Double (5) and addition (6), as the ternaries on a constant bit are free.
+            (_, Mode::Constant, num_bits) => (11 * (num_bits - 1), 11 * (num_bits - 1)),
+            // Double (5), addition with a constant (3), and ternary (2), as the first ternary is on constants.
+            (Mode::Constant, _, num_bits) => (10 * (num_bits - 1), 10 * (num_bits - 1)),
+            // Double (5), addition (6), and ternary (2), as the first double and addition are on zero.
+            (_, _, num_bits) => (13 * (num_bits - 1) + 2, 13 * (num_bits - 1) + 2),
+        };
+        Count(
+            Measurement::UpperBound(7 * case.2),
+            Measurement::Exact(0),
+            Measurement::Exact(num_private),
+            Measurement::Exact(num_constraints),
+        )
+    }
+}
+
+impl<E: Environment> OutputMode<dyn MulByBitsLE> for Group<E> {
+    type Case = (Mode, Mode, u64);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            // The product is zero, or is computed on constants.
+            (_, _, 0) | (Mode::Constant, Mode::Constant, _) => Mode::Constant,
+            // The product is the group element itself.
+            (mode, Mode::Constant, 1) => *mode,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Returns the case for `mul_by_bits_le` on the given group element and bits.
+    fn mul_by_bits_le_case(base: &Group<Circuit>, bits_le: &[Boolean<Circuit>]) -> (Mode, Mode, u64) {
+        let mode = match bits_le.iter().all(|bit| bit.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let num_bits = match mode.is_constant() {
+            // Exclude the most-significant zero bits.
+            true => bits_le.iter().rposition(|bit| bit.eject_value()).map_or(0, |index| index + 1),
+            false => bits_le.len(),
+        };
+        (base.eject_mode(), mode, num_bits as u64)
+    }
+
+    fn check_mul_by_bits_le(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element and scalar.
+            let base: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let scalar: console::Scalar<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let expected = base * scalar;
+
+            // Initialize the inputs.
+            let a = Group::<Circuit>::new(mode_a, base);
+            let bits_le = Scalar::<Circuit>::new(mode_b, scalar).to_bits_le();
+            let mut bits_be = bits_le.clone();
+            bits_be.reverse();
+
+            let (num_private, num_constraints) = Circuit::scope(format!("MulByBitsLE {mode_a} {mode_b} {i}"), || {
+                let candidate = a.mul_by_bits_le(&bits_le);
+                assert_eq!(expected, candidate.eject_value());
+                let case = mul_by_bits_le_case(&a, &bits_le);
+                assert_count!(Group<Circuit>, MulByBitsLE, &case);
+                assert_output_mode!(Group<Circuit>, MulByBitsLE, &case, candidate);
+                (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+            });
+
+            // Ensure the cost matches double-and-add on the big-endian bits.
+            Circuit::scope(format!("MulByBitsBE {mode_a} {mode_b} {i}"), || {
+                let candidate = &a * bits_be.as_slice();
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(num_private, Circuit::num_private_in_scope());
+                assert_eq!(num_constraints, Circuit::num_constraints_in_scope());
+            });
+
+            // Ensure the result matches reconstructing the scalar, and that the range check is saved.
+            Circuit::scope(format!("Mul {mode_a} {mode_b} {i}"), || {
+                let candidate = &a * Scalar::from_bits_le(&bits_le);
+                assert_eq!(expected, candidate.eject_value());
+                match mode_b.is_constant() {
+                    true => assert_eq!(num_constraints, Circuit::num_constraints_in_scope()),
+                    false => assert!(num_constraints < Circuit::num_constraints_in_scope()),
+                }
+            });
+
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_mul_by_bits_le() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_mul_by_bits_le(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_by_bits_le_short() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Private] {
+                for num_bits in [0, 1, 2, 8] {
+                    // Sample a random element and bits.
+                    let base: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                    let bits = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+                    let expected = bits.iter().rev().fold(
+                        console::Group::<<Circuit as Environment>::Network>::zero(),
+                        |output, bit| match bit {
+                            true => output.double() + base,
+                            false => output.double(),
+                        },
+                    );
+
+                    // Initialize the inputs.
+                    let a = Group::<Circuit>::new(mode_a, base);
+                    let bits_le = bits.iter().map(|bit| Boolean::<Circuit>::new(mode_b, *bit)).collect::<Vec<_>>();
+
+                    Circuit::scope(format!("MulByBitsLE {mode_a} {mode_b} {num_bits}"), || {
+                        let candidate = a.mul_by_bits_le(&bits_le);
+                        assert_eq!(expected, candidate.eject_value());
+                        let case = mul_by_bits_le_case(&a, &bits_le);
+                        assert_count!(Group<Circuit>, MulByBitsLE, &case);
+                        assert_output_mode!(Group<Circuit>, MulByBitsLE, &case, candidate);
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+}
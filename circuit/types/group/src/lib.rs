@@ -19,6 +19,7 @@
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
 mod helpers;
+pub use helpers::mul_by_bits::MulByBitsLE;
 
 pub mod add;
 pub mod double;
@@ -93,6 +93,52 @@ impl<E: Environment> FromBits for Scalar<E> {
     }
 }
 
+impl<E: Environment> Metrics<dyn FromBits<Boolean = Boolean<E>>> for Scalar<E> {
+    type Case = Vec<Mode>;
+
+    fn count(modes: &Self::Case) -> Count {
+        let size_in_data_bits = console::Scalar::<E::Network>::size_in_data_bits();
+        let size_in_bits = console::Scalar::<E::Network>::size_in_bits();
+
+        // Count the number of non-constant bits within, and in excess of, the scalar field size.
+        let num_variable_bits = modes.iter().take(size_in_bits).filter(|mode| !mode.is_constant()).count();
+        let num_excess_bits = modes.iter().skip(size_in_bits).filter(|mode| !mode.is_constant()).count() as u64;
+
+        // The excess bits are folded with `num_excess_bits - 1` OR gates, and enforced to be zero.
+        let excess_count = match num_excess_bits {
+            0 => Count::zero(),
+            num_excess_bits => Count::is(0, 0, num_excess_bits - 1, num_excess_bits),
+        };
+
+        // The range check is only performed if there are more than `size_in_data_bits` bits.
+        // It allocates `ScalarField::MODULUS - 1` as a constant, and compares it to the reconstructed scalar.
+        // The reconstruction of the scalar is a linear combination, and is free.
+        let range_check_count = match (modes.len() > size_in_data_bits, num_variable_bits) {
+            (false, _) => Count::zero(),
+            (true, 0) => Count::is(2, 0, 0, 0),
+            (true, num_variable_bits) if num_variable_bits == size_in_bits => Count::is(1, 0, 253, 255),
+            (true, _) => Count::less_than(2, 0, 253, 255),
+        };
+
+        excess_count + range_check_count
+    }
+}
+
+impl<E: Environment> OutputMode<dyn FromBits<Boolean = Boolean<E>>> for Scalar<E> {
+    type Case = Vec<Mode>;
+
+    /// Returns the mode of the reconstructed scalar, given the modes of the bits.
+    ///
+    /// The scalar is `Constant` if and only if each of the bits up to `E::ScalarField::size_in_bits()`
+    /// is constant. Otherwise, it is `Private`, as it is a linear combination of the bits.
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().take(console::Scalar::<E::Network>::size_in_bits()).all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,13 +159,26 @@ mod tests {
                 let candidate = Scalar::<Circuit>::from_bits_le(&given_bits);
                 assert_eq!(expected, candidate.eject_value());
                 assert_eq!(expected_size_in_bits, candidate.bits_le.get().unwrap().len());
-                assert_eq!(expected_size_in_bits, candidate.to_bits_le().len());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                let case = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+                assert_count!(FromBits<Boolean>() => Scalar, &case);
+                assert_output_mode!(FromBits<Boolean>() => Scalar, &case, candidate);
+
+                // Ensure the scalar round trips through `to_bits_le`, at no additional cost.
+                let candidate_bits = candidate.to_bits_le();
+                assert_eq!(expected_size_in_bits, candidate_bits.len());
+                for (expected_bit, candidate_bit) in expected.to_bits_le().iter().zip_eq(&candidate_bits) {
+                    assert_eq!(*expected_bit, candidate_bit.eject_value());
+                }
                 assert_scope!(num_constants, num_public, num_private, num_constraints);
             });
 
             // Add excess zero bits.
             let candidate = vec![given_bits, vec![Boolean::new(mode, false); i as usize]].concat();
 
+            let case = candidate.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+
             Circuit::scope(&format!("Excess {} {}", mode, i), || {
                 let candidate = Scalar::<Circuit>::from_bits_le(&candidate);
                 assert_eq!(expected, candidate.eject_value());
@@ -132,6 +191,8 @@ mod tests {
                         assert_scope!(num_constants, num_public, num_private + i.saturating_sub(1), num_constraints + i)
                     }
                 };
+                assert_count!(FromBits<Boolean>() => Scalar, &case);
+                assert_output_mode!(FromBits<Boolean>() => Scalar, &case, candidate);
             });
         }
     }
@@ -149,13 +210,26 @@ mod tests {
                 let candidate = Scalar::<Circuit>::from_bits_be(&given_bits);
                 assert_eq!(expected, candidate.eject_value());
                 assert_eq!(expected_size_in_bits, candidate.bits_le.get().unwrap().len());
-                assert_eq!(expected_size_in_bits, candidate.to_bits_be().len());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                let case = given_bits.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+                assert_count!(FromBits<Boolean>() => Scalar, &case);
+                assert_output_mode!(FromBits<Boolean>() => Scalar, &case, candidate);
+
+                // Ensure the scalar round trips through `to_bits_be`, at no additional cost.
+                let candidate_bits = candidate.to_bits_be();
+                assert_eq!(expected_size_in_bits, candidate_bits.len());
+                for (expected_bit, candidate_bit) in expected.to_bits_be().iter().zip_eq(&candidate_bits) {
+                    assert_eq!(*expected_bit, candidate_bit.eject_value());
+                }
                 assert_scope!(num_constants, num_public, num_private, num_constraints);
             });
 
             // Add excess zero bits.
             let candidate = vec![vec![Boolean::new(mode, false); i as usize], given_bits].concat();
 
+            let case = candidate.iter().map(|bit| bit.eject_mode()).collect::<Vec<_>>();
+
             Circuit::scope(&format!("Excess {} {}", mode, i), || {
                 let candidate = Scalar::<Circuit>::from_bits_be(&candidate);
                 assert_eq!(expected, candidate.eject_value());
@@ -168,10 +242,47 @@ mod tests {
                         assert_scope!(num_constants, num_public, num_private + i.saturating_sub(1), num_constraints + i)
                     }
                 };
+                assert_count!(FromBits<Boolean>() => Scalar, &case);
+                assert_output_mode!(FromBits<Boolean>() => Scalar, &case, candidate);
             });
         }
     }
 
+    #[test]
+    fn test_from_bits_le_out_of_range() {
+        let size_in_bits = console::Scalar::<<Circuit as Environment>::Network>::size_in_bits();
+
+        // Ensure a nonzero excess bit is rejected.
+        let mut given_bits = vec![Boolean::<Circuit>::new(Mode::Private, false); size_in_bits + 1];
+        given_bits[size_in_bits] = Boolean::new(Mode::Private, true);
+        let _candidate = Scalar::<Circuit>::from_bits_le(&given_bits);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure `ScalarField::MODULUS` is rejected.
+        let mut given_bits = (-console::Scalar::<<Circuit as Environment>::Network>::one())
+            .to_bits_le()
+            .iter()
+            .map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit))
+            .collect::<Vec<_>>();
+        // Set the least significant bit, as `MODULUS - 1` is even.
+        given_bits[0] = Boolean::new(Mode::Private, true);
+        let _candidate = Scalar::<Circuit>::from_bits_le(&given_bits);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure `ScalarField::MODULUS - 1` is accepted.
+        let given_bits = (-console::Scalar::<<Circuit as Environment>::Network>::one())
+            .to_bits_le()
+            .iter()
+            .map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit))
+            .collect::<Vec<_>>();
+        let candidate = Scalar::<Circuit>::from_bits_le(&given_bits);
+        assert_eq!(-console::Scalar::one(), candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_from_bits_le_constant() {
         check_from_bits_le(Mode::Constant, 2, 0, 0, 0);
@@ -81,6 +81,21 @@ fn coinbase_puzzle_prove(c: &mut Criterion) {
     }
 }
 
+#[cfg(feature = "setup")]
+fn coinbase_puzzle_prove_deterministic(c: &mut Criterion) {
+    for degree in [(1 << 13) - 1] {
+        let (puzzle, epoch_challenge, address) = CoinbasePuzzleInst::bench_setup(0, degree).unwrap();
+
+        c.bench_function(&format!("CoinbasePuzzle::Prove (deterministic) 2^{}", ((degree + 1) as f64).log2()), |b| {
+            let mut nonce = 0u64;
+            b.iter(|| {
+                nonce += 1;
+                puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+            })
+        });
+    }
+}
+
 #[cfg(feature = "setup")]
 fn coinbase_puzzle_accumulate(c: &mut Criterion) {
     let rng = &mut thread_rng();
@@ -143,7 +158,8 @@ fn coinbase_puzzle_verify(c: &mut Criterion) {
 criterion_group! {
     name = coinbase_puzzle;
     config = Criterion::default().sample_size(10);
-    targets = coinbase_puzzle_trim, coinbase_puzzle_prove, coinbase_puzzle_accumulate, coinbase_puzzle_verify,
+    targets = coinbase_puzzle_trim, coinbase_puzzle_prove, coinbase_puzzle_prove_deterministic, coinbase_puzzle_accumulate,
+        coinbase_puzzle_verify,
 }
 
 criterion_main!(coinbase_puzzle);
@@ -115,4 +115,71 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_legacy_coinbase() -> Result<()> {
+        use crate::coinbase_puzzle::PartialSolution;
+        use snarkvm_algorithms::polycommit::kzg10::{KZGCommitment, KZGProof};
+
+        let mut rng = TestRng::default();
+        let genesis = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+
+        // Sample a coinbase solution.
+        let mut partial_solutions = vec![];
+        for _ in 0..4 {
+            let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+        }
+        let coinbase = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+
+        // Construct a block with the coinbase solution, on top of the genesis block.
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            1,
+            1,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+        )?;
+        let header = Header::from(
+            Uniform::rand(&mut rng),
+            genesis.header().transactions_root(),
+            coinbase.to_accumulator_point()?,
+            metadata,
+        )?;
+        let private_key = PrivateKey::new(&mut rng)?;
+        let expected =
+            Block::new(&private_key, genesis.hash(), header, genesis.transactions().clone(), Some(coinbase), &mut rng)?;
+        let coinbase = expected.coinbase().unwrap();
+
+        // Write the block in the legacy layout, where the partial solutions are written without a version.
+        let mut legacy_bytes = 0u16.to_bytes_le()?;
+        legacy_bytes.extend_from_slice(&expected.hash().to_bytes_le()?);
+        legacy_bytes.extend_from_slice(&expected.previous_hash().to_bytes_le()?);
+        legacy_bytes.extend_from_slice(&expected.header().to_bytes_le()?);
+        legacy_bytes.extend_from_slice(&expected.transactions().to_bytes_le()?);
+        legacy_bytes.push(1u8);
+        legacy_bytes.extend_from_slice(&(coinbase.len() as u32).to_bytes_le()?);
+        for partial_solution in coinbase.partial_solutions() {
+            legacy_bytes.extend_from_slice(&partial_solution.address().to_bytes_le()?);
+            legacy_bytes.extend_from_slice(&partial_solution.nonce().to_bytes_le()?);
+            legacy_bytes.extend_from_slice(&partial_solution.commitment().to_bytes_le()?);
+        }
+        legacy_bytes.extend_from_slice(&coinbase.proof().to_bytes_le()?);
+        legacy_bytes.extend_from_slice(&expected.signature().to_bytes_le()?);
+
+        // Ensure the byte representation is unchanged from the legacy layout.
+        assert_eq!(legacy_bytes, expected.to_bytes_le()?);
+        // Ensure the legacy layout is decodable by the streaming reader, and by the binary deserializer.
+        let mut reader = &legacy_bytes[..];
+        assert_eq!(expected, Block::read_le(&mut reader)?);
+        assert!(reader.is_empty());
+        let legacy_bytes_with_size_encoding =
+            [(legacy_bytes.len() as u64).to_le_bytes().to_vec(), legacy_bytes].concat();
+        assert_eq!(expected, bincode::deserialize(&legacy_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
 }
@@ -25,23 +25,56 @@ use blake2::Digest;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// Returns the coefficient for the given `counter`, from the hash of the input.
+fn hash_to_coefficient<F: PrimeField>(hash: &[u8], counter: u32) -> F {
+    let mut input_with_counter = [0u8; 36];
+    input_with_counter[..32].copy_from_slice(hash);
+    input_with_counter[32..].copy_from_slice(&counter.to_le_bytes());
+    F::from_bytes_le_mod_order(&blake2::Blake2b512::digest(input_with_counter))
+}
+
 pub fn hash_to_coefficients<F: PrimeField>(input: &[u8], num_coefficients: u32) -> Vec<F> {
     // Hash the input.
     let hash = blake2::Blake2s256::digest(input);
     // Hash with a counter and return the coefficients.
-    cfg_into_iter!(0..num_coefficients)
-        .map(|counter| {
-            let mut input_with_counter = [0u8; 36];
-            input_with_counter[..32].copy_from_slice(&hash);
-            input_with_counter[32..].copy_from_slice(&counter.to_le_bytes());
-            F::from_bytes_le_mod_order(&blake2::Blake2b512::digest(input_with_counter))
-        })
-        .collect()
+    cfg_into_iter!(0..num_coefficients).map(|counter| hash_to_coefficient(&hash, counter)).collect()
+}
+
+/// Hashes the input into `num_coefficients` coefficients, which are passed to `callback` in order,
+/// in chunks of at most `chunk_size` coefficients. Only one chunk of coefficients is held in memory at a time.
+pub fn hash_to_coefficients_in_chunks<F: PrimeField>(
+    input: &[u8],
+    num_coefficients: u32,
+    chunk_size: u32,
+    mut callback: impl FnMut(&[F]) -> Result<()>,
+) -> Result<()> {
+    ensure!(chunk_size != 0, "The chunk size cannot be zero");
+
+    // Hash the input.
+    let hash = blake2::Blake2s256::digest(input);
+    // Hash with a counter, and pass the coefficients to the callback in chunks.
+    let mut start = 0;
+    while start < num_coefficients {
+        let end = start.saturating_add(chunk_size).min(num_coefficients);
+        let chunk = cfg_into_iter!(start..end).map(|counter| hash_to_coefficient(&hash, counter)).collect::<Vec<F>>();
+        callback(&chunk)?;
+        start = end;
+    }
+    Ok(())
 }
 
 pub fn hash_to_polynomial<F: PrimeField>(input: &[u8], degree: u32) -> DensePolynomial<F> {
-    // Hash the input into coefficients.
-    let coefficients = hash_to_coefficients(input, degree + 1);
+    // Hash the input, and derive the polynomial from the hash.
+    hash_digest_to_polynomial(&blake2::Blake2s256::digest(input), degree)
+}
+
+/// Returns the polynomial of the given degree, from the (BLAKE2s-256) hash of the input, as in `hash_to_polynomial`.
+///
+/// This allows the hash of the input to be computed incrementally, e.g. from a hasher that has absorbed
+/// a shared prefix of the input.
+pub fn hash_digest_to_polynomial<F: PrimeField>(hash: &[u8], degree: u32) -> DensePolynomial<F> {
+    // Hash with a counter into coefficients.
+    let coefficients = cfg_into_iter!(0..degree + 1).map(|counter| hash_to_coefficient(hash, counter)).collect();
     // Construct the polynomial from the coefficients.
     DensePolynomial::from_coefficients_vec(coefficients)
 }
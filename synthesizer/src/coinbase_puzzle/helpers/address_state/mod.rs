@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use blake2::Digest;
+
+/// The address-dependent state of the coinbase puzzle, which is derived once per epoch challenge and address,
+/// so that a prover iterating over many nonces for one address only performs the nonce-dependent work.
+///
+/// The state holds the hasher of the prover polynomial input, after absorbing
+/// `( epoch_number || epoch_block_hash || address )`.
+#[derive(Clone)]
+pub struct AddressState<N: Network> {
+    /// The epoch challenge.
+    epoch_challenge: EpochChallenge<N>,
+    /// The prover address.
+    address: Address<N>,
+    /// The hasher of the prover polynomial input, after absorbing the epoch and address.
+    hasher: blake2::Blake2s256,
+}
+
+impl<N: Network> AddressState<N> {
+    /// Initializes a new address state from the given epoch challenge and address.
+    pub fn new(epoch_challenge: &EpochChallenge<N>, address: Address<N>) -> Result<Self> {
+        // Absorb the input prefix, defined as '( epoch_number || epoch_block_hash )', followed by the address.
+        let mut hasher = blake2::Blake2s256::new();
+        hasher.update(CoinbasePuzzle::prover_polynomial_input_prefix(epoch_challenge)?);
+        hasher.update(address.to_bytes_le()?);
+        // Return the address state.
+        Ok(Self { epoch_challenge: epoch_challenge.clone(), address, hasher })
+    }
+
+    /// Returns the epoch challenge.
+    pub const fn epoch_challenge(&self) -> &EpochChallenge<N> {
+        &self.epoch_challenge
+    }
+
+    /// Returns the prover address.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the prover polynomial for the given nonce, by absorbing only the nonce into the prepared hasher.
+    pub fn prover_polynomial(&self, nonce: u64) -> DensePolynomial<<N::PairingCurve as PairingEngine>::Fr> {
+        let hash = self.hasher.clone().chain_update(nonce.to_le_bytes()).finalize();
+        hash_digest_to_polynomial(&hash, self.epoch_challenge.degree())
+    }
+}
+
+impl<N: Network> Debug for AddressState<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AddressState")
+            .field("epoch_challenge", &self.epoch_challenge)
+            .field("address", &self.address)
+            .finish()
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A builder for a coinbase solution, which collects the prover solutions to accumulate,
+/// and rejects any prover solution that does not meet the minimum proof target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinbaseSolutionBuilder<N: Network> {
+    /// The minimum proof target of each prover solution.
+    minimum_target: u64,
+    /// The prover solutions to accumulate.
+    prover_solutions: Vec<ProverSolution<N>>,
+}
+
+impl<N: Network> Default for CoinbaseSolutionBuilder<N> {
+    /// Returns a builder with no prover solutions, and a minimum proof target of `0`.
+    fn default() -> Self {
+        Self { minimum_target: 0, prover_solutions: Vec::new() }
+    }
+}
+
+impl<N: Network> CoinbaseSolutionBuilder<N> {
+    /// Initializes a new builder with no prover solutions, and a minimum proof target of `0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the minimum proof target of each prover solution.
+    ///
+    /// Returns an error if a prover solution that was already added does not meet the minimum proof target.
+    pub fn with_minimum_target(mut self, minimum_target: u64) -> Result<Self> {
+        for prover_solution in &self.prover_solutions {
+            Self::check_target(prover_solution, minimum_target)?;
+        }
+        self.minimum_target = minimum_target;
+        Ok(self)
+    }
+
+    /// Returns the minimum proof target of each prover solution.
+    pub const fn minimum_target(&self) -> u64 {
+        self.minimum_target
+    }
+
+    /// Returns the prover solutions that were added.
+    pub fn prover_solutions(&self) -> &[ProverSolution<N>] {
+        &self.prover_solutions
+    }
+
+    /// Adds the given prover solution, if it meets the minimum proof target.
+    ///
+    /// Returns an error naming the commitment of the prover solution if it does not meet the minimum proof target.
+    pub fn add(&mut self, prover_solution: ProverSolution<N>) -> Result<()> {
+        Self::check_target(&prover_solution, self.minimum_target)?;
+        self.prover_solutions.push(prover_solution);
+        Ok(())
+    }
+
+    /// Returns the coinbase solution that accumulates the added prover solutions, for the given epoch challenge.
+    ///
+    /// # Note
+    /// As in `CoinbasePuzzle::accumulate_unchecked`, this method does *not* check that the prover solutions are valid.
+    pub fn build(
+        self,
+        coinbase_puzzle: &CoinbasePuzzle<N>,
+        epoch_challenge: &EpochChallenge<N>,
+    ) -> Result<CoinbaseSolution<N>> {
+        coinbase_puzzle.accumulate_unchecked(epoch_challenge, &self.prover_solutions)
+    }
+
+    /// Ensures the given prover solution meets the given minimum proof target.
+    fn check_target(prover_solution: &ProverSolution<N>, minimum_target: u64) -> Result<()> {
+        let target = prover_solution.to_target()?;
+        ensure!(
+            target >= minimum_target,
+            "Prover solution '{}' does not meet the minimum proof target ({target} < {minimum_target})",
+            prover_solution.commitment()
+        );
+        Ok(())
+    }
+}
@@ -15,35 +15,227 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use snarkvm_utilities::CanonicalSerialize;
 
 impl<N: Network> FromBytes for CoinbaseSolution<N> {
     /// Reads the coinbase solution from the buffer.
+    ///
+    /// Note: The partial solutions are read without the version, as this layout is part of the block format.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let partial_solutions_len: u32 = FromBytes::read_le(&mut reader)?;
 
         let mut partial_solutions = Vec::with_capacity(partial_solutions_len as usize);
         for _ in 0..partial_solutions_len {
-            let individual_puzzle_solution: PartialSolution<N> = FromBytes::read_le(&mut reader)?;
+            let individual_puzzle_solution = PartialSolution::read_le_v1(&mut reader)?;
             partial_solutions.push(individual_puzzle_solution);
         }
 
         let proof = KZGProof::read_le(&mut reader)?;
 
-        Ok(Self::new(partial_solutions, proof))
+        // Ensure the partial solutions are in the canonical order.
+        let coinbase_solution = Self { partial_solutions, proof };
+        if !coinbase_solution.is_canonical() {
+            return Err(error("The partial solutions of the coinbase solution are not in canonical order"));
+        }
+        Ok(coinbase_solution)
     }
 }
 
 impl<N: Network> ToBytes for CoinbaseSolution<N> {
     /// Writes the coinbase solution to the buffer.
+    ///
+    /// Note: The partial solutions are written without the version, as this layout is part of the block format.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         (self.partial_solutions.len() as u32).write_le(&mut writer)?;
 
         for individual_puzzle_solution in &self.partial_solutions {
-            individual_puzzle_solution.write_le(&mut writer)?;
+            individual_puzzle_solution.write_le_v1(&mut writer)?;
         }
 
         self.proof.write_le(&mut writer)
     }
+
+    /// Returns the coinbase solution as a byte array in little-endian order.
+    fn to_bytes_le(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(self.estimated_size());
+        self.write_le(&mut bytes)?;
+        // Ensure the size model matches the serialization.
+        debug_assert_eq!(bytes.len(), self.estimated_size(), "The estimated coinbase solution size is incorrect");
+        Ok(bytes)
+    }
+}
+
+impl<N: Network> CoinbaseSolution<N> {
+    /// Returns the size of the byte representation of the coinbase solution, without serializing it.
+    ///
+    /// The size is `( num_partial_solutions || partial_solutions || proof )`, where the number of partial solutions
+    /// is a `u32`, each partial solution is of a fixed size, and the proof is in its compressed form.
+    pub fn estimated_size(&self) -> usize {
+        4 + self.partial_solutions.len() * PARTIAL_SOLUTION_UNVERSIONED_SIZE_IN_BYTES + self.proof.compressed_size()
+    }
+
+    /// Returns the coinbase solution in a compact byte representation, where the prover addresses
+    /// are dictionary-encoded, as `( addresses || (address_index, nonce, commitment)* || proof )`.
+    ///
+    /// Each distinct address is written once, and each partial solution refers to its address by index.
+    /// This is smaller than `to_bytes_le` when many partial solutions share an address.
+    pub fn to_compact_bytes(&self) -> Result<Vec<u8>> {
+        // Collect the distinct addresses, in the order of their first occurrence.
+        let mut addresses = Vec::new();
+        let mut indices = HashMap::new();
+        for solution in &self.partial_solutions {
+            indices.entry(solution.address()).or_insert_with(|| {
+                addresses.push(solution.address());
+                addresses.len() as u32 - 1
+            });
+        }
+
+        let mut bytes = Vec::new();
+        // Write the addresses.
+        (addresses.len() as u32).write_le(&mut bytes)?;
+        for address in &addresses {
+            address.write_le(&mut bytes)?;
+        }
+        // Write the partial solutions, with the index of their address.
+        (self.partial_solutions.len() as u32).write_le(&mut bytes)?;
+        for solution in &self.partial_solutions {
+            indices[&solution.address()].write_le(&mut bytes)?;
+            solution.nonce().write_le(&mut bytes)?;
+            solution.commitment().write_le(&mut bytes)?;
+        }
+        // Write the proof.
+        self.proof.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Initializes a coinbase solution from the compact byte representation of `to_compact_bytes`.
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+
+        // Read the addresses, of which there are at most as many as partial solutions.
+        let num_addresses = u32::read_le(&mut reader)?;
+        ensure!(
+            num_addresses as usize <= N::MAX_PROVER_SOLUTIONS,
+            "The compact coinbase solution contains too many addresses ({num_addresses})"
+        );
+        let addresses = (0..num_addresses).map(|_| Address::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Ensure the addresses are distinct.
+        ensure!(!has_duplicates(addresses.iter()), "The compact coinbase solution contains duplicate addresses");
+
+        // Read the partial solutions.
+        let num_partial_solutions = u32::read_le(&mut reader)?;
+        // Ensure the number of partial solutions does not exceed `MAX_PROVER_SOLUTIONS`, before allocating.
+        ensure!(
+            num_partial_solutions as usize <= N::MAX_PROVER_SOLUTIONS,
+            "The compact coinbase solution contains too many partial solutions ({num_partial_solutions})"
+        );
+        let mut partial_solutions = Vec::with_capacity(num_partial_solutions as usize);
+        for _ in 0..num_partial_solutions {
+            let index = u32::read_le(&mut reader)?;
+            let address = match addresses.get(index as usize) {
+                Some(address) => *address,
+                None => bail!("The compact coinbase solution contains an invalid address index ({index})"),
+            };
+            let nonce = u64::read_le(&mut reader)?;
+            let commitment = KZGCommitment::read_le(&mut reader)?;
+
+            // Ensure the partial solution is well-formed.
+            let partial_solution = PartialSolution::new(address, nonce, commitment);
+            partial_solution.validate()?;
+            partial_solutions.push(partial_solution);
+        }
+
+        // Read the proof.
+        let proof = KZGProof::read_le(&mut reader)?;
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "The compact coinbase solution contains {} trailing bytes", reader.len());
+
+        // Ensure the partial solutions are in the canonical order.
+        let coinbase_solution = Self { partial_solutions, proof };
+        ensure!(
+            coinbase_solution.is_canonical(),
+            "The partial solutions of the coinbase solution are not in canonical order"
+        );
+        Ok(coinbase_solution)
+    }
+
+    /// Returns the coinbase solution encoded as a difference against the given reference solution,
+    /// as `( removed_indices || added_partial_solutions || proof )`.
+    ///
+    /// The removed indices refer to the partial solutions of `reference` that are not in `self`, and the added
+    /// partial solutions are those of `self` that are not in `reference`. The proof is always included,
+    /// as it is specific to the set of partial solutions. See `CoinbaseSolution::apply_diff` to decode.
+    pub fn encode_diff(&self, reference: &Self) -> Result<Vec<u8>> {
+        // Determine the partial solutions to remove from, and add to, the reference.
+        let solutions = self.partial_solutions.iter().collect::<HashSet<_>>();
+        let reference_solutions = reference.partial_solutions.iter().collect::<HashSet<_>>();
+        let removed = (0..reference.partial_solutions.len() as u32)
+            .filter(|index| !solutions.contains(&reference.partial_solutions[*index as usize]))
+            .collect::<Vec<_>>();
+        let added = self
+            .partial_solutions
+            .iter()
+            .filter(|solution| !reference_solutions.contains(solution))
+            .collect::<Vec<_>>();
+
+        let mut bytes = Vec::new();
+        // Write the indices of the removed partial solutions.
+        (removed.len() as u32).write_le(&mut bytes)?;
+        for index in &removed {
+            index.write_le(&mut bytes)?;
+        }
+        // Write the added partial solutions.
+        (added.len() as u32).write_le(&mut bytes)?;
+        for solution in added {
+            solution.write_le(&mut bytes)?;
+        }
+        // Write the proof.
+        self.proof.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Initializes a coinbase solution by applying the difference from `CoinbaseSolution::encode_diff`
+    /// to the given reference solution.
+    pub fn apply_diff(reference: &Self, diff: &[u8]) -> Result<Self> {
+        let mut reader = diff;
+
+        // Read the indices of the removed partial solutions, which must be strictly increasing and in bounds.
+        let num_removed = u32::read_le(&mut reader)?;
+        let mut is_removed = vec![false; reference.partial_solutions.len()];
+        let mut previous = None;
+        for _ in 0..num_removed {
+            let index = u32::read_le(&mut reader)?;
+            ensure!(previous < Some(index), "The coinbase solution diff contains unordered removed indices");
+            match is_removed.get_mut(index as usize) {
+                Some(is_removed) => *is_removed = true,
+                None => bail!("The coinbase solution diff contains an invalid removed index ({index})"),
+            }
+            previous = Some(index);
+        }
+
+        // Retain the partial solutions of the reference that are not removed.
+        let mut partial_solutions = reference
+            .partial_solutions
+            .iter()
+            .zip(&is_removed)
+            .filter(|(_, is_removed)| !**is_removed)
+            .map(|(solution, _)| *solution)
+            .collect::<Vec<_>>();
+
+        // Read the added partial solutions.
+        let num_added = u32::read_le(&mut reader)?;
+        for _ in 0..num_added {
+            partial_solutions.push(PartialSolution::read_le(&mut reader)?);
+        }
+        ensure!(!has_duplicates(&partial_solutions), "The coinbase solution diff contains duplicate partial solutions");
+
+        // Read the proof.
+        let proof = KZGProof::read_le(&mut reader)?;
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "The coinbase solution diff contains {} trailing bytes", reader.len());
+
+        Ok(Self::new(partial_solutions, proof))
+    }
 }
 
 #[cfg(test)]
@@ -51,6 +243,8 @@ mod tests {
     use super::*;
     use console::{account::PrivateKey, network::Testnet3};
 
+    use rand::seq::SliceRandom;
+
     type CurrentNetwork = Testnet3;
 
     #[test]
@@ -74,4 +268,210 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_legacy() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_solutions in [0, 1, 2, 5, 16] {
+            // Sample a new coinbase solution.
+            let mut partial_solutions = vec![];
+            for _ in 0..num_solutions {
+                let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+                let address = Address::try_from(private_key)?;
+
+                partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+            }
+            let expected = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+
+            // Write the coinbase solution in the legacy layout, without the version of each partial solution.
+            let mut legacy_bytes = (expected.len() as u32).to_bytes_le()?;
+            for partial_solution in expected.partial_solutions() {
+                legacy_bytes.extend_from_slice(&partial_solution.to_bytes_le()?[1..]);
+            }
+            legacy_bytes.extend_from_slice(&expected.proof().to_bytes_le()?);
+
+            // Ensure the byte representation is unchanged from the legacy layout.
+            assert_eq!(legacy_bytes, expected.to_bytes_le()?);
+            // Ensure the legacy layout is decodable, from a reader and from bytes.
+            let mut reader = &legacy_bytes[..];
+            assert_eq!(expected, CoinbaseSolution::read_le(&mut reader)?);
+            assert!(reader.is_empty());
+            assert_eq!(expected, CoinbaseSolution::from_bytes_le(&legacy_bytes)?);
+            // Ensure a truncated layout is rejected.
+            let truncated_bytes = &legacy_bytes[..legacy_bytes.len() - 1];
+            assert!(CoinbaseSolution::<CurrentNetwork>::from_bytes_le(truncated_bytes).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimated_size() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_solutions in [0, 1, 2, 5, 16] {
+            // Sample a new coinbase solution.
+            let mut partial_solutions = vec![];
+            for _ in 0..num_solutions {
+                let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+                let address = Address::try_from(private_key)?;
+
+                partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+            }
+            let candidate = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+
+            // Ensure the estimated size matches the byte representation.
+            assert_eq!(candidate.to_bytes_le()?.len(), candidate.estimated_size());
+            let mut bytes = vec![];
+            candidate.write_le(&mut bytes)?;
+            assert_eq!(bytes.len(), candidate.estimated_size());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_order() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the partial solutions.
+        let mut partial_solutions = vec![];
+        for _ in 0..10 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+        }
+        let proof = KZGProof { w: rng.gen(), random_v: None };
+
+        // Construct the expected coinbase solution.
+        let expected = CoinbaseSolution::new(partial_solutions.clone(), proof);
+        assert!(expected.is_canonical());
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure the serialization is identical regardless of the input order.
+        for _ in 0..10 {
+            partial_solutions.shuffle(&mut rng);
+            let candidate = CoinbaseSolution::new(partial_solutions.clone(), proof);
+            assert_eq!(expected, candidate);
+            assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        }
+
+        // Ensure a non-canonical encoding is rejected.
+        let mut reversed = expected.partial_solutions().to_vec();
+        reversed.reverse();
+        let candidate = CoinbaseSolution { partial_solutions: reversed, proof };
+        assert!(!candidate.is_canonical());
+        assert!(CoinbaseSolution::<CurrentNetwork>::read_le(&candidate.to_bytes_le()?[..]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the prover addresses.
+        let addresses = (0..3)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Sample a new coinbase solution, with partial solutions that share addresses.
+        let partial_solutions = (0..20)
+            .map(|_| {
+                let address = *addresses.choose(&mut rng).unwrap();
+                PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()))
+            })
+            .collect::<Vec<_>>();
+        let expected = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+
+        // Check the compact byte representation.
+        let compact_bytes = expected.to_compact_bytes()?;
+        assert_eq!(expected, CoinbaseSolution::from_compact_bytes(&compact_bytes)?);
+        assert!(compact_bytes.len() < expected.to_bytes_le()?.len());
+
+        // Ensure truncated and trailing bytes are rejected.
+        let candidate = &compact_bytes[..compact_bytes.len() - 1];
+        assert!(CoinbaseSolution::<CurrentNetwork>::from_compact_bytes(candidate).is_err());
+        let candidate = [&compact_bytes[..], &[0u8]].concat();
+        assert!(CoinbaseSolution::<CurrentNetwork>::from_compact_bytes(&candidate).is_err());
+
+        // Ensure an out-of-bounds address index is rejected.
+        let mut candidate = compact_bytes.clone();
+        let num_addresses = u32::from_le_bytes(compact_bytes[..4].try_into()?);
+        let offset = 4 + num_addresses as usize * 32 + 4;
+        candidate[offset..offset + 4].copy_from_slice(&num_addresses.to_le_bytes());
+        assert!(CoinbaseSolution::<CurrentNetwork>::from_compact_bytes(&candidate).is_err());
+
+        // Ensure an excessive number of addresses is rejected, before allocating.
+        let mut candidate = compact_bytes.clone();
+        candidate[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let error = CoinbaseSolution::<CurrentNetwork>::from_compact_bytes(&candidate).unwrap_err();
+        assert!(error.to_string().contains("too many addresses"));
+
+        // Ensure an excessive number of partial solutions is rejected, before allocating.
+        let mut candidate = compact_bytes.clone();
+        let offset = 4 + num_addresses as usize * 32;
+        candidate[offset..offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let error = CoinbaseSolution::<CurrentNetwork>::from_compact_bytes(&candidate).unwrap_err();
+        assert!(error.to_string().contains("too many partial solutions"));
+
+        // Ensure the compact encoding of distinct addresses round-trips.
+        let partial_solutions = addresses
+            .iter()
+            .map(|address| PartialSolution::new(*address, u64::rand(&mut rng), KZGCommitment(rng.gen())))
+            .collect::<Vec<_>>();
+        let expected = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+        assert_eq!(expected, CoinbaseSolution::from_compact_bytes(&expected.to_compact_bytes()?)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the partial solutions.
+        let partial_solutions = (0..30)
+            .map(|_| {
+                let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+                Ok(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Construct overlapping reference and target solutions.
+        let reference =
+            CoinbaseSolution::new(partial_solutions[..20].to_vec(), KZGProof { w: rng.gen(), random_v: None });
+        let expected =
+            CoinbaseSolution::new(partial_solutions[5..25].to_vec(), KZGProof { w: rng.gen(), random_v: None });
+
+        // Ensure the diff round-trips, and is smaller than the full encoding.
+        let diff = expected.encode_diff(&reference)?;
+        assert_eq!(expected, CoinbaseSolution::apply_diff(&reference, &diff)?);
+        assert!(diff.len() < expected.to_bytes_le()?.len());
+
+        // Ensure the diff against itself, disjoint, and empty solutions round-trip.
+        let disjoint =
+            CoinbaseSolution::new(partial_solutions[25..].to_vec(), KZGProof { w: rng.gen(), random_v: None });
+        let empty = CoinbaseSolution::new(vec![], KZGProof { w: rng.gen(), random_v: None });
+        for (target, reference) in [
+            (&expected, &expected),
+            (&expected, &disjoint),
+            (&disjoint, &expected),
+            (&expected, &empty),
+            (&empty, &expected),
+        ] {
+            let diff = target.encode_diff(reference)?;
+            assert_eq!(*target, CoinbaseSolution::apply_diff(reference, &diff)?);
+        }
+
+        // Ensure an out-of-bounds removed index, truncated bytes, and trailing bytes are rejected.
+        let mut candidate = diff.clone();
+        candidate[4..8].copy_from_slice(&(reference.len() as u32).to_le_bytes());
+        assert!(CoinbaseSolution::apply_diff(&reference, &candidate).is_err());
+        assert!(CoinbaseSolution::apply_diff(&reference, &diff[..diff.len() - 1]).is_err());
+        let candidate = [&diff[..], &[0u8]].concat();
+        assert!(CoinbaseSolution::apply_diff(&reference, &candidate).is_err());
+
+        Ok(())
+    }
 }
@@ -14,11 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod builder;
+pub use builder::*;
+
 mod bytes;
 mod serialize;
 mod string;
 
 use super::*;
+use snarkvm_fields::{PrimeField, Zero};
+
+use blake2::Digest;
+use std::collections::{HashMap, HashSet};
 
 /// The coinbase puzzle solution constructed by accumulating the individual prover solutions.
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -31,10 +38,29 @@ pub struct CoinbaseSolution<N: Network> {
 
 impl<N: Network> CoinbaseSolution<N> {
     /// Initializes a new instance of a coinbase solution.
-    pub const fn new(partial_solutions: Vec<PartialSolution<N>>, proof: PuzzleProof<N>) -> Self {
+    ///
+    /// The partial solutions are sorted into the canonical order, which is defined as
+    /// the ascending order of the (little-endian) bytes of their puzzle commitments.
+    pub fn new(mut partial_solutions: Vec<PartialSolution<N>>, proof: PuzzleProof<N>) -> Self {
+        // Sort the partial solutions into the canonical order.
+        partial_solutions.sort_by_cached_key(Self::canonical_order_key);
         Self { partial_solutions, proof }
     }
 
+    /// Returns the key used to sort the partial solutions into the canonical order,
+    /// which is defined as the (little-endian) bytes of the puzzle commitment.
+    pub(crate) fn canonical_order_key(partial_solution: &PartialSolution<N>) -> Vec<u8> {
+        // Note: Serializing a puzzle commitment into a vector does not fail in practice.
+        partial_solution.commitment().to_bytes_le().unwrap_or_default()
+    }
+
+    /// Returns `true` if the partial solutions are in the canonical order.
+    pub fn is_canonical(&self) -> bool {
+        self.partial_solutions
+            .windows(2)
+            .all(|pair| Self::canonical_order_key(&pair[0]) <= Self::canonical_order_key(&pair[1]))
+    }
+
     /// Returns the partial solutions.
     pub fn partial_solutions(&self) -> &[PartialSolution<N>] {
         &self.partial_solutions
@@ -60,6 +86,21 @@ impl<N: Network> CoinbaseSolution<N> {
         self.partial_solutions.is_empty()
     }
 
+    /// Returns the partial solutions in `self` that are not in `other`, as determined by their puzzle commitments.
+    ///
+    /// Note: A coinbase solution holds a single proof for all of its partial solutions,
+    /// so the individual prover solutions (and their proofs) can not be recovered from it.
+    pub fn difference(&self, other: &Self) -> Vec<PartialSolution<N>> {
+        // Collect the puzzle commitments of `other`.
+        let other_commitments = other.puzzle_commitments().collect::<HashSet<_>>();
+        // Return the partial solutions that are not in `other`.
+        self.partial_solutions
+            .iter()
+            .filter(|solution| !other_commitments.contains(&solution.commitment()))
+            .copied()
+            .collect()
+    }
+
     /// Returns the cumulative sum of the prover solutions.
     pub fn to_cumulative_proof_target(&self) -> Result<u128> {
         // Compute the cumulative target as a u128.
@@ -68,6 +109,116 @@ impl<N: Network> CoinbaseSolution<N> {
         })
     }
 
+    /// Ensures every partial solution belongs to the given epoch challenge, by re-deriving its puzzle commitment
+    /// from the epoch polynomial and its prover polynomial (which is derived from its address and nonce).
+    ///
+    /// Returns an error naming the puzzle commitment of the first inconsistent partial solution.
+    /// This catches prover solutions from different epochs being mixed while assembling a coinbase solution.
+    /// Note: The commitment of a partial solution does not bind to the epoch by itself, so the proving key is required.
+    pub fn verify_epoch_consistency(
+        &self,
+        proving_key: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+    ) -> Result<()> {
+        for partial_solution in &self.partial_solutions {
+            // Re-derive the puzzle commitment for the epoch challenge.
+            let prover_polynomial = partial_solution.to_prover_polynomial(epoch_challenge)?;
+            let product_evaluations =
+                CoinbasePuzzle::product_evaluations(proving_key, epoch_challenge, &prover_polynomial);
+            let (commitment, _) = KZG10::commit_lagrange(
+                &proving_key.lagrange_basis(),
+                &product_evaluations,
+                None,
+                &Default::default(),
+                None,
+            )?;
+
+            // Ensure the puzzle commitment matches.
+            ensure!(
+                PuzzleCommitment::<N>::from(commitment) == partial_solution.commitment(),
+                "The partial solution with puzzle commitment '{}' does not belong to epoch {}",
+                partial_solution.commitment(),
+                epoch_challenge.epoch_number()
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the accumulator of the partial solutions, which is a KZG commitment to the polynomial
+    /// `Z(X) = (X - h_0) * ... * (X - h_{n-1})`, where `h_i` is the hash of the `i`-th partial solution.
+    ///
+    /// A party holding only the accumulator can check that a partial solution is included,
+    /// given a constant-size membership witness (see `CoinbaseSolution::verify_membership`).
+    pub fn accumulator(&self, proving_key: &CoinbaseProvingKey<N>) -> Result<KZGCommitment<N::PairingCurve>> {
+        let roots = self.partial_solutions.iter().map(Self::to_membership_point).collect::<Result<Vec<_>>>()?;
+        Self::commit_to_roots(proving_key, &roots)
+    }
+
+    /// Returns the membership witness for the given partial solution, which is a KZG proof
+    /// that `Z(X)` vanishes at the hash of the partial solution.
+    pub fn membership_witness(
+        &self,
+        proving_key: &CoinbaseProvingKey<N>,
+        partial_solution: &PartialSolution<N>,
+    ) -> Result<PuzzleProof<N>> {
+        // Ensure the partial solution is included.
+        let index = match self.partial_solutions.iter().position(|solution| solution == partial_solution) {
+            Some(index) => index,
+            None => bail!("The partial solution is not included in the coinbase solution"),
+        };
+
+        // Commit to the quotient `Z(X) / (X - h)`, which is the product over the remaining roots.
+        let mut roots = self.partial_solutions.iter().map(Self::to_membership_point).collect::<Result<Vec<_>>>()?;
+        roots.remove(index);
+        let quotient = Self::commit_to_roots(proving_key, &roots)?;
+
+        Ok(KZGProof { w: quotient.0, random_v: None })
+    }
+
+    /// Returns `true` if the given witness proves the partial solution is included in the given accumulator.
+    pub fn verify_membership(
+        verifying_key: &CoinbaseVerifyingKey<N>,
+        accumulator: &KZGCommitment<N::PairingCurve>,
+        partial_solution: &PartialSolution<N>,
+        witness: &PuzzleProof<N>,
+    ) -> Result<bool> {
+        // Ensure the witness is not hiding.
+        ensure!(!witness.is_hiding(), "The membership witness must not be hiding");
+        // Check that `Z(h) = 0`, where `h` is the hash of the partial solution.
+        let point = Self::to_membership_point(partial_solution)?;
+        Ok(KZG10::check(verifying_key, accumulator, point, Zero::zero(), witness)?)
+    }
+
+    /// Returns the hash of the given partial solution, as a root of the accumulator polynomial.
+    fn to_membership_point(partial_solution: &PartialSolution<N>) -> Result<<N::PairingCurve as PairingEngine>::Fr> {
+        let hash = blake2::Blake2b512::digest(partial_solution.to_bytes_le()?);
+        Ok(<N::PairingCurve as PairingEngine>::Fr::from_bytes_le_mod_order(&hash))
+    }
+
+    /// Returns a KZG commitment to the polynomial with the given roots.
+    fn commit_to_roots(
+        proving_key: &CoinbaseProvingKey<N>,
+        roots: &[<N::PairingCurve as PairingEngine>::Fr],
+    ) -> Result<KZGCommitment<N::PairingCurve>> {
+        // Ensure the polynomial fits within the product domain.
+        let domain_elements = proving_key.product_domain_elements();
+        ensure!(
+            roots.len() < domain_elements.len(),
+            "Cannot accumulate {} partial solutions in a domain of size {}",
+            roots.len(),
+            domain_elements.len()
+        );
+
+        // Evaluate the polynomial over the product domain, and commit to it in the Lagrange basis.
+        let evaluations = domain_elements
+            .iter()
+            .map(|element| roots.iter().map(|root| *element - root).product())
+            .collect::<Vec<_>>();
+        let (commitment, _) =
+            KZG10::commit_lagrange(&proving_key.lagrange_basis(), &evaluations, None, &Default::default(), None)?;
+        Ok(commitment)
+    }
+
     /// Returns the accumulator challenge point.
     pub fn to_accumulator_point(&self) -> Result<Field<N>> {
         let mut challenge_points =
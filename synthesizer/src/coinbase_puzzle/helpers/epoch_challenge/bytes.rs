@@ -42,6 +42,47 @@ impl<N: Network> ToBytes for EpochChallenge<N> {
     }
 }
 
+impl<N: Network> EpochChallenge<N> {
+    /// Reads the epoch challenge from a buffer, and streams the coefficients of its epoch polynomial to `callback`,
+    /// in order, in chunks of at most `chunk_size` coefficients. Unlike `read_le`, the epoch polynomial
+    /// (and its evaluations over the product domain) is never materialized, which bounds the peak memory.
+    ///
+    /// Returns the epoch number, the epoch block hash, and the hash of the epoch polynomial,
+    /// which is computed incrementally, and is equal to `to_polynomial_hash`.
+    pub fn read_le_streaming<R: Read, F: FnMut(&[<N::PairingCurve as PairingEngine>::Fr])>(
+        mut reader: R,
+        chunk_size: usize,
+        mut callback: F,
+    ) -> Result<(u32, N::BlockHash, [u8; 32])> {
+        // Read the epoch number.
+        let epoch_number: u32 = FromBytes::read_le(&mut reader)?;
+        // Read the epoch block hash.
+        let epoch_block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
+        // Read the epoch degree.
+        let degree: u32 = FromBytes::read_le(&mut reader)?;
+
+        // Ensure the degree is valid, as in `EpochChallenge::new`.
+        CoinbasePuzzle::<N>::product_domain(degree)?;
+        let num_coefficients = degree.checked_add(1).ok_or_else(|| anyhow!("Degree is too large"))?;
+
+        // Hash the coefficients of the epoch polynomial, and pass them to the callback in chunks.
+        let input = Self::epoch_polynomial_input(epoch_number, epoch_block_hash)?;
+        let chunk_size = u32::try_from(chunk_size).unwrap_or(u32::MAX);
+        let mut hasher = blake2::Blake2s256::new();
+        hash_to_coefficients_in_chunks(&input, num_coefficients, chunk_size, |chunk| {
+            for coefficient in chunk {
+                hasher.update(coefficient.to_bytes_le()?);
+            }
+            callback(chunk);
+            Ok(())
+        })?;
+
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&hasher.finalize());
+        Ok((epoch_number, epoch_block_hash, hash))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,4 +114,38 @@ mod tests {
             assert!(EpochChallenge::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         }
     }
+
+    #[test]
+    fn test_read_le_streaming() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // Sample a new epoch challenge.
+            let degree = rng.gen_range(1..1u32 << 10);
+            let expected = EpochChallenge::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), degree).unwrap();
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_hash = expected.to_polynomial_hash().unwrap();
+
+            for chunk_size in [1, 7, degree as usize, degree as usize + 1, usize::MAX] {
+                // Stream the coefficients of the epoch polynomial.
+                let mut coefficients = Vec::new();
+                let (epoch_number, epoch_block_hash, candidate_hash) =
+                    EpochChallenge::<CurrentNetwork>::read_le_streaming(&expected_bytes[..], chunk_size, |chunk| {
+                        assert!(!chunk.is_empty() && chunk.len() <= chunk_size);
+                        coefficients.extend_from_slice(chunk);
+                    })
+                    .unwrap();
+
+                // Ensure the streaming hash equals the full hash.
+                assert_eq!(expected.epoch_number(), epoch_number);
+                assert_eq!(expected.epoch_block_hash(), epoch_block_hash);
+                assert_eq!(expected_hash, candidate_hash);
+                assert_eq!(expected.epoch_polynomial().coeffs(), &coefficients[..]);
+            }
+
+            // Ensure a zero chunk size and truncated bytes are rejected.
+            assert!(EpochChallenge::<CurrentNetwork>::read_le_streaming(&expected_bytes[..], 0, |_| ()).is_err());
+            assert!(EpochChallenge::<CurrentNetwork>::read_le_streaming(&expected_bytes[1..], 1, |_| ()).is_err());
+        }
+    }
 }
@@ -19,7 +19,9 @@ mod bytes;
 use snarkvm_algorithms::fft::Evaluations as EvaluationsOnDomain;
 
 use super::*;
-use crate::coinbase_puzzle::hash_to_polynomial;
+use crate::coinbase_puzzle::{hash_to_coefficients_in_chunks, hash_to_polynomial};
+
+use blake2::Digest;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EpochChallenge<N: Network> {
@@ -37,7 +39,7 @@ impl<N: Network> EpochChallenge<N> {
     /// Initializes a new epoch challenge.
     pub fn new(epoch_number: u32, epoch_block_hash: N::BlockHash, degree: u32) -> Result<Self> {
         // Construct the 'input' as '( epoch_number || epoch_block_hash )'
-        let input: Vec<u8> = epoch_number.to_le_bytes().into_iter().chain(epoch_block_hash.to_bytes_le()?).collect();
+        let input = Self::epoch_polynomial_input(epoch_number, epoch_block_hash)?;
 
         let product_domain = CoinbasePuzzle::<N>::product_domain(degree)?;
 
@@ -82,4 +84,68 @@ impl<N: Network> EpochChallenge<N> {
         let degree = self.degree();
         degree.checked_add(1).ok_or_else(|| anyhow!("Epoch polynomial degree ({degree} + 1) overflows"))
     }
+
+    /// Returns the hash of the epoch polynomial, defined as the BLAKE2s hash of its (little-endian) coefficients.
+    pub fn to_polynomial_hash(&self) -> Result<[u8; 32]> {
+        let mut hasher = blake2::Blake2s256::new();
+        for coefficient in self.epoch_polynomial.coeffs() {
+            hasher.update(coefficient.to_bytes_le()?);
+        }
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&hasher.finalize());
+        Ok(hash)
+    }
+
+    /// Returns the input for the epoch polynomial, defined as `( epoch_number || epoch_block_hash )`.
+    fn epoch_polynomial_input(epoch_number: u32, epoch_block_hash: N::BlockHash) -> Result<Vec<u8>> {
+        Ok(epoch_number.to_le_bytes().into_iter().chain(epoch_block_hash.to_bytes_le()?).collect())
+    }
+
+    /// Returns the suggested proof target for a prover with the given `hashrate` (in solutions per second),
+    /// such that the prover finds a solution that meets the target roughly every `share_interval_secs` seconds.
+    ///
+    /// The target of a solution is computed as `u64::MAX / h`, where `h` is (approximately) uniform in `[1, u64::MAX]`.
+    /// Thus, a solution meets a proof target `t` with probability `~1 / t`, and the expected number
+    /// of attempts to find such a solution is `~t`. As a prover attempts `hashrate * share_interval_secs`
+    /// solutions per share interval, the suggested target is `hashrate * share_interval_secs`.
+    ///
+    /// The suggested target saturates at `u64::MAX`, and is at least `1`, which is met by every solution.
+    pub fn suggested_target(hashrate: u64, share_interval_secs: u64) -> u64 {
+        hashrate.saturating_mul(share_interval_secs).max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_suggested_target() {
+        // Ensure a higher hashrate yields a higher target.
+        let mut previous_target = 0;
+        for hashrate in [1, 10, 100, 1_000, 10_000, 100_000] {
+            let target = EpochChallenge::<CurrentNetwork>::suggested_target(hashrate, 10);
+            assert!(target > previous_target);
+            assert_eq!(hashrate * 10, target);
+            previous_target = target;
+        }
+
+        // Ensure a longer share interval yields a higher target.
+        assert!(
+            EpochChallenge::<CurrentNetwork>::suggested_target(100, 20)
+                > EpochChallenge::<CurrentNetwork>::suggested_target(100, 10)
+        );
+
+        // Ensure extreme values saturate, rather than overflow.
+        assert_eq!(u64::MAX, EpochChallenge::<CurrentNetwork>::suggested_target(u64::MAX, u64::MAX));
+        assert_eq!(u64::MAX, EpochChallenge::<CurrentNetwork>::suggested_target(u64::MAX, 2));
+
+        // Ensure degenerate values clamp to the minimum target.
+        assert_eq!(1, EpochChallenge::<CurrentNetwork>::suggested_target(0, 10));
+        assert_eq!(1, EpochChallenge::<CurrentNetwork>::suggested_target(10, 0));
+        assert_eq!(1, EpochChallenge::<CurrentNetwork>::suggested_target(0, 0));
+    }
 }
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod address_state;
+pub use address_state::*;
+
 mod coinbase_solution;
 pub use coinbase_solution::*;
 
@@ -29,7 +32,22 @@ pub use prover_solution::*;
 mod puzzle_commitment;
 pub use puzzle_commitment::*;
 
-use crate::coinbase_puzzle::{hash_commitment, hash_commitments, CoinbasePuzzle};
+mod solution_pool;
+pub use solution_pool::*;
+
+mod submission_limiter;
+pub use submission_limiter::*;
+
+mod tagged_solution;
+pub use tagged_solution::*;
+
+mod target_histogram;
+pub use target_histogram::*;
+
+mod verify_cache;
+pub use verify_cache::*;
+
+use crate::coinbase_puzzle::{hash_commitment, hash_commitments, hash_digest_to_polynomial, CoinbasePuzzle};
 use console::{account::Address, prelude::*, types::Field};
 use snarkvm_algorithms::{
     fft::{domain::FFTPrecomputation, DensePolynomial, EvaluationDomain},
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A builder for a partial solution, which is populated one field at a time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PartialSolutionBuilder<N: Network> {
+    /// The address of the prover, if set.
+    address: Option<Address<N>>,
+    /// The nonce for the solution, if set.
+    nonce: Option<u64>,
+    /// The commitment for the solution, if set.
+    commitment: Option<PuzzleCommitment<N>>,
+}
+
+impl<N: Network> Default for PartialSolutionBuilder<N> {
+    /// Returns a builder with no fields set.
+    fn default() -> Self {
+        Self { address: None, nonce: None, commitment: None }
+    }
+}
+
+impl<N: Network> PartialSolution<N> {
+    /// Returns a builder for a partial solution, with no fields set.
+    pub fn builder() -> PartialSolutionBuilder<N> {
+        PartialSolutionBuilder::default()
+    }
+}
+
+impl<N: Network> PartialSolutionBuilder<N> {
+    /// Sets the address of the prover.
+    pub fn address(mut self, address: Address<N>) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the nonce for the solution.
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Sets the commitment for the solution.
+    pub fn commitment<C: Into<PuzzleCommitment<N>>>(mut self, commitment: C) -> Self {
+        self.commitment = Some(commitment.into());
+        self
+    }
+
+    /// Returns the partial solution, or an error naming the first field that is not set.
+    pub fn build(self) -> Result<PartialSolution<N>> {
+        let address = match self.address {
+            Some(address) => address,
+            None => bail!("The partial solution is missing the 'address'"),
+        };
+        let nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => bail!("The partial solution is missing the 'nonce'"),
+        };
+        let commitment = match self.commitment {
+            Some(commitment) => commitment,
+            None => bail!("The partial solution is missing the 'commitment'"),
+        };
+        Ok(PartialSolution::new(address, nonce, commitment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_builder() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let nonce = u64::rand(&mut rng);
+        let commitment = KZGCommitment(rng.gen());
+        let expected = PartialSolution::new(address, nonce, commitment);
+
+        // Ensure a fully-populated builder builds the partial solution, in any order.
+        let candidate = PartialSolution::builder().address(address).nonce(nonce).commitment(commitment).build()?;
+        assert_eq!(expected, candidate);
+        let candidate = PartialSolution::builder().commitment(commitment).nonce(nonce).address(address).build()?;
+        assert_eq!(expected, candidate);
+
+        // Ensure a later setter overrides an earlier one.
+        let candidate =
+            PartialSolution::builder().address(address).nonce(0).nonce(nonce).commitment(commitment).build()?;
+        assert_eq!(expected, candidate);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_missing_field() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let nonce = u64::rand(&mut rng);
+        let commitment = KZGCommitment(rng.gen());
+
+        // Ensure each missing field is named in the error.
+        let error = PartialSolution::builder().nonce(nonce).commitment(commitment).build().unwrap_err();
+        assert!(error.to_string().contains("'address'"));
+        let error = PartialSolution::builder().address(address).commitment(commitment).build().unwrap_err();
+        assert!(error.to_string().contains("'nonce'"));
+        let error = PartialSolution::<CurrentNetwork>::builder().address(address).nonce(nonce).build().unwrap_err();
+        assert!(error.to_string().contains("'commitment'"));
+        assert!(PartialSolution::<CurrentNetwork>::builder().build().is_err());
+
+        Ok(())
+    }
+}
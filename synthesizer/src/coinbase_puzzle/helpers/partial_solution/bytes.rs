@@ -19,23 +19,77 @@ use super::*;
 impl<N: Network> FromBytes for PartialSolution<N> {
     /// Reads the partial solution from the buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let address: Address<N> = FromBytes::read_le(&mut reader)?;
-        let nonce = u64::read_le(&mut reader)?;
-        let commitment = KZGCommitment::read_le(&mut reader)?;
+        // Read the version, and dispatch to the parser for the version.
+        let version = u8::read_le(&mut reader)?;
+        match version {
+            1 => Self::read_le_v1(reader),
+            _ => Err(error(format!("Invalid partial solution version ({version})"))),
+        }
+    }
 
-        Ok(Self::new(address, nonce, commitment))
+    /// Returns the partial solution from the given bytes, in either the versioned layout,
+    /// or the layout without the version, which are distinguished by their length.
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self> {
+        match bytes.len() {
+            PARTIAL_SOLUTION_UNVERSIONED_SIZE_IN_BYTES => Ok(Self::read_le_v1(bytes)?),
+            _ => Ok(Self::read_le(bytes)?),
+        }
     }
 }
 
 impl<N: Network> ToBytes for PartialSolution<N> {
     /// Writes the partial solution to the buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        PARTIAL_SOLUTION_VERSION.write_le(&mut writer)?;
+        // Write the partial solution.
+        self.write_le_v1(writer)
+    }
+}
+
+impl<N: Network> PartialSolution<N> {
+    /// Reads the partial solution from the buffer, as `( address || nonce || commitment )`.
+    ///
+    /// This is the layout without the version, which is embedded in prover solutions and coinbase solutions,
+    /// as their byte representations are part of the block format, and must remain decodable.
+    pub(crate) fn read_le_v1<R: Read>(mut reader: R) -> IoResult<Self> {
+        let address: Address<N> = FromBytes::read_le(&mut reader)?;
+        let nonce = u64::read_le(&mut reader)?;
+        let commitment = KZGCommitment::read_le(&mut reader)?;
+
+        // Ensure the partial solution is well-formed.
+        let partial_solution = Self::new(address, nonce, commitment);
+        partial_solution.validate().map_err(|e| error(e.to_string()))?;
+        Ok(partial_solution)
+    }
+
+    /// Writes the partial solution to the buffer, as `( address || nonce || commitment )`,
+    /// in the layout without the version.
+    pub(crate) fn write_le_v1<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.address.write_le(&mut writer)?;
         self.nonce.write_le(&mut writer)?;
         self.commitment.write_le(&mut writer)
     }
 }
 
+impl<N: Network> PartialSolution<N> {
+    /// The size of the partial solution in bytes.
+    pub const SIZE: usize = PARTIAL_SOLUTION_SIZE_IN_BYTES;
+
+    /// Returns the partial solution as a fixed-size byte array, with the same layout as `to_bytes_le`.
+    pub fn to_array(&self) -> Result<[u8; PARTIAL_SOLUTION_SIZE_IN_BYTES]> {
+        match self.to_bytes_le()?.try_into() {
+            Ok(array) => Ok(array),
+            Err(bytes) => bail!("Invalid partial solution size ({} bytes, expected {})", bytes.len(), Self::SIZE),
+        }
+    }
+
+    /// Initializes a partial solution from a fixed-size byte array, with the same layout as `to_bytes_le`.
+    pub fn from_array(bytes: &[u8; PARTIAL_SOLUTION_SIZE_IN_BYTES]) -> Result<Self> {
+        Ok(Self::read_le(&bytes[..])?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +97,8 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    const ITERATIONS: u64 = 100;
+
     #[test]
     fn test_bytes() -> Result<()> {
         let mut rng = TestRng::default();
@@ -57,6 +113,85 @@ mod tests {
         assert_eq!(expected, PartialSolution::read_le(&expected_bytes[..])?);
         assert!(PartialSolution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
 
+        // Ensure the version is written first.
+        assert_eq!(PARTIAL_SOLUTION_VERSION, expected_bytes[0]);
+        assert_eq!(PartialSolution::<CurrentNetwork>::SIZE, expected_bytes.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_unknown_version() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample a new partial solution.
+        let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure an unknown version is rejected.
+        for version in [0u8, 2, u8::MAX] {
+            let mut candidate = expected_bytes.clone();
+            candidate[0] = version;
+            let result = PartialSolution::<CurrentNetwork>::read_le(&candidate[..]);
+            assert!(result.unwrap_err().to_string().contains("Invalid partial solution version"));
+        }
+        // Ensure the layout without the version is rejected by the reader, which cannot distinguish it.
+        assert!(PartialSolution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_legacy() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution, and write it in the legacy layout.
+            let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+            let mut legacy_bytes = expected.address().to_bytes_le()?;
+            legacy_bytes.extend_from_slice(&expected.nonce().to_le_bytes());
+            legacy_bytes.extend_from_slice(&expected.commitment().to_bytes_le()?);
+            assert_eq!(PARTIAL_SOLUTION_UNVERSIONED_SIZE_IN_BYTES, legacy_bytes.len());
+            assert_eq!(legacy_bytes[..], expected.to_bytes_le()?[1..]);
+
+            // Ensure the layout without the version matches the legacy layout.
+            let mut unversioned_bytes = Vec::new();
+            expected.write_le_v1(&mut unversioned_bytes)?;
+            assert_eq!(legacy_bytes, unversioned_bytes);
+            assert_eq!(expected, PartialSolution::read_le_v1(&legacy_bytes[..])?);
+
+            // Ensure both the legacy and the versioned layouts are decodable from bytes.
+            assert_eq!(expected, PartialSolution::from_bytes_le(&legacy_bytes)?);
+            assert_eq!(expected, PartialSolution::from_bytes_le(&expected.to_bytes_le()?)?);
+            // Ensure a truncated legacy layout is rejected.
+            assert!(PartialSolution::<CurrentNetwork>::from_bytes_le(&legacy_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_array() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution.
+            let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+
+            // Check the array representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_array = expected.to_array()?;
+            assert_eq!(PartialSolution::<CurrentNetwork>::SIZE, expected_bytes.len());
+            assert_eq!(&expected_bytes[..], &expected_array[..]);
+            assert_eq!(expected, PartialSolution::from_array(&expected_array)?);
+        }
         Ok(())
     }
 }
@@ -14,13 +14,38 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod builder;
+pub use builder::*;
+
 mod bytes;
 mod serialize;
 mod string;
 
+#[cfg(feature = "proto")]
+mod proto;
+#[cfg(feature = "proto")]
+pub use proto::*;
+
 use super::*;
+use console::{
+    account::{PrivateKey, Signature},
+    types::Group,
+};
 use snarkvm_algorithms::crypto_hash::sha256d_to_u64;
 
+use blake2::Digest;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// The version of the byte representation of a partial solution.
+pub const PARTIAL_SOLUTION_VERSION: u8 = 1;
+
+/// The size of a partial solution in bytes, defined as `version (1) || address (32) || nonce (8) || commitment (48)`.
+pub const PARTIAL_SOLUTION_SIZE_IN_BYTES: usize = 89;
+
+/// The size of a partial solution in bytes, in the layout without the version, which is embedded in
+/// prover solutions and coinbase solutions, defined as `address (32) || nonce (8) || commitment (48)`.
+pub const PARTIAL_SOLUTION_UNVERSIONED_SIZE_IN_BYTES: usize = 88;
+
 /// The partial solution for the coinbase puzzle from a prover.
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PartialSolution<N: Network> {
@@ -43,6 +68,11 @@ impl<N: Network> PartialSolution<N> {
         self.address
     }
 
+    /// Returns the address to credit with the payout for the solution.
+    pub const fn payout_address(&self) -> Address<N> {
+        self.address
+    }
+
     /// Returns the nonce for the solution.
     pub const fn nonce(&self) -> u64 {
         self.nonce
@@ -53,6 +83,75 @@ impl<N: Network> PartialSolution<N> {
         self.commitment
     }
 
+    /// Ensures the address of the prover is a valid account address,
+    /// which is a nonzero group element that is on the curve and in the prime-order subgroup.
+    pub fn validate(&self) -> Result<()> {
+        // Ensure the address is nonzero.
+        ensure!(!self.address.is_zero(), "The prover address cannot be zero");
+        // Ensure the address is on the curve and in the prime-order subgroup, by recovering it from its x-coordinate.
+        match Group::from_x_coordinate(self.address.to_x_coordinate()) {
+            Ok(group) if group == *self.address => Ok(()),
+            _ => bail!("The prover address is not a valid group element"),
+        }
+    }
+
+    /// Returns the number of shares for each prover address in the given solutions.
+    ///
+    /// A commitment is counted at most once, and a duplicate commitment is credited
+    /// to the address of the first solution that contains it.
+    pub fn accumulate_shares(solutions: &[Self]) -> HashMap<Address<N>, u64> {
+        let mut commitments = HashSet::with_capacity(solutions.len());
+        let mut shares = HashMap::new();
+        for solution in solutions.iter().filter(|solution| commitments.insert(solution.commitment())) {
+            *shares.entry(solution.payout_address()).or_insert(0u64) += 1;
+        }
+        shares
+    }
+
+    /// Returns the given solutions, tagged with their epoch number, grouped by epoch number in ascending order.
+    ///
+    /// Within each epoch, the solutions retain their given order. To process the most recent epoch first,
+    /// iterate over the result in reverse.
+    pub fn bucket_by_epoch(solutions: Vec<(u32, Self)>) -> BTreeMap<u32, Vec<Self>> {
+        let mut buckets = BTreeMap::<u32, Vec<Self>>::new();
+        for (epoch_number, solution) in solutions {
+            buckets.entry(epoch_number).or_default().push(solution);
+        }
+        buckets
+    }
+
+    /// Returns `true` if the partial solution has the same address and commitment as the given solution.
+    ///
+    /// Unlike `==`, the nonce is ignored, so that a resubmission of the same solution
+    /// with a cosmetically different nonce is detected.
+    pub fn is_resubmission_of(&self, other: &Self) -> bool {
+        self.address == other.address && self.commitment == other.commitment
+    }
+
+    /// Returns `true` if the byte representation of the partial solution matches that of the given solution.
+    ///
+    /// As serialization is deterministic, this is implied by `==`, which compares the fields of the solutions.
+    /// This method compares the wire format, for auditing that two solutions are identical byte-for-byte.
+    pub fn bytes_eq(&self, other: &Self) -> Result<bool> {
+        Ok(self.to_bytes_le()? == other.to_bytes_le()?)
+    }
+
+    /// Returns a signature on the partial solution, under the given private key.
+    ///
+    /// The signature is over the byte representation of the partial solution, so that
+    /// a submission can be authenticated, and can not be altered without invalidating the signature.
+    pub fn sign<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Signature<N>> {
+        private_key.sign_bytes(&self.to_bytes_le()?, rng)
+    }
+
+    /// Returns `true` if the given signature on the partial solution is valid for the given address.
+    pub fn verify_signature(&self, signature: &Signature<N>, address: &Address<N>) -> bool {
+        match self.to_bytes_le() {
+            Ok(message) => signature.verify_bytes(address, &message),
+            Err(_) => false,
+        }
+    }
+
     /// Returns the prover polynomial.
     pub fn to_prover_polynomial(
         &self,
@@ -62,8 +161,446 @@ impl<N: Network> PartialSolution<N> {
     }
 
     /// Returns the target of the solution.
+    ///
+    /// Note: The target is derived from the puzzle commitment alone, and does not depend on the epoch challenge,
+    /// the challenge point, or the prover polynomial. Thus, filtering solutions by target requires no caching.
     pub fn to_target(&self) -> Result<u64> {
         let hash_to_u64 = sha256d_to_u64(&self.commitment.to_bytes_le()?);
         if hash_to_u64 == 0 { Ok(u64::MAX) } else { Ok(u64::MAX / hash_to_u64) }
     }
+
+    /// Returns the difficulty of the solution, which is the expected number of attempts to find a solution
+    /// with at least its target, as `to_target` is `u64::MAX / hash` for a uniformly-distributed `hash`.
+    pub fn difficulty(&self) -> Result<f64> {
+        Ok(Self::difficulty_from_target(self.to_target()?))
+    }
+
+    /// Returns the difficulty of the solution, formatted with an SI suffix (e.g. `1.50M`).
+    pub fn difficulty_string(&self) -> Result<String> {
+        Ok(Self::format_difficulty(self.difficulty()?))
+    }
+
+    /// Returns the difficulty for the given proof target, which is at least `1`.
+    pub fn difficulty_from_target(target: u64) -> f64 {
+        // Note: A target of `0` is not produced by `to_target`, and is treated as the minimum difficulty.
+        target.max(1) as f64
+    }
+
+    /// Returns the given difficulty, formatted with two decimal places and an SI suffix.
+    pub fn format_difficulty(difficulty: f64) -> String {
+        const SUFFIXES: [&str; 7] = ["", "K", "M", "G", "T", "P", "E"];
+
+        let mut value = difficulty;
+        let mut index = 0;
+        while value >= 1000.0 && index < SUFFIXES.len() - 1 {
+            value /= 1000.0;
+            index += 1;
+        }
+        format!("{value:.2}{}", SUFFIXES[index])
+    }
+
+    /// Returns the ID of the partial solution, which is the BLAKE2s-256 hash of its byte representation.
+    ///
+    /// The ID is content-addressed: it is stable across serialization and processes, and changes
+    /// if any field of the partial solution changes. Thus, it is suitable as a database key.
+    pub fn id(&self) -> Result<[u8; 32]> {
+        // Hash the byte representation of the partial solution.
+        let hash = blake2::Blake2s256::digest(self.to_bytes_le()?);
+        let mut id = [0u8; 32];
+        id.copy_from_slice(&hash);
+        Ok(id)
+    }
+
+    /// Returns a 128-bit fingerprint of the partial solution, derived from its byte representation.
+    ///
+    /// The fingerprint is intended for probabilistic deduplication (e.g. in a bloom filter),
+    /// and is not a substitute for verifying the solution.
+    pub fn fingerprint(&self) -> Result<u128> {
+        // Compute the ID of the partial solution.
+        let hash = self.id()?;
+        // Truncate the hash to 128 bits.
+        let mut fingerprint = [0u8; 16];
+        fingerprint.copy_from_slice(&hash[..16]);
+        Ok(u128::from_le_bytes(fingerprint))
+    }
+
+    /// Returns the partial solution as field elements, as `( address || nonce || commitment )`.
+    ///
+    /// The address is encoded as its x-coordinate, and the nonce is encoded as a field element.
+    /// The commitment is encoded as the little-endian bits of its (compressed) byte representation,
+    /// packed into field elements of `Field::size_in_data_bits()` bits each.
+    pub fn to_fields(&self) -> Result<Vec<Field<N>>> {
+        // Encode the address and the nonce.
+        let mut fields = vec![self.address.to_x_coordinate(), Field::from_u64(self.nonce)];
+        // Encode the commitment.
+        for chunk in self.commitment.to_bytes_le()?.to_bits_le().chunks(Field::<N>::size_in_data_bits()) {
+            fields.push(Field::from_bits_le(chunk)?);
+        }
+        Ok(fields)
+    }
+
+    /// Initializes the partial solution from field elements, as written by `PartialSolution::to_fields`.
+    pub fn from_fields(fields: &[Field<N>]) -> Result<Self> {
+        // Retrieve the data size.
+        let size_in_data_bits = Field::<N>::size_in_data_bits();
+        // Compute the number of bits and field elements in the commitment, which follows the address and the nonce.
+        let num_commitment_bits = 8 * (PARTIAL_SOLUTION_UNVERSIONED_SIZE_IN_BYTES - 32 - 8);
+        let num_commitment_fields = (num_commitment_bits + size_in_data_bits - 1) / size_in_data_bits;
+
+        // Ensure the number of field elements is correct.
+        ensure!(
+            fields.len() == 2 + num_commitment_fields,
+            "Expected {} field elements for the partial solution, found {}",
+            2 + num_commitment_fields,
+            fields.len()
+        );
+
+        // Decode the address.
+        let address = Address::new(Group::from_x_coordinate(fields[0])?);
+
+        // Decode the nonce, and ensure it fits within 64 bits.
+        let nonce_bytes = fields[1].to_bytes_le()?;
+        ensure!(nonce_bytes[8..].iter().all(|byte| *byte == 0), "The nonce of the partial solution exceeds 64 bits");
+        let nonce = u64::read_le(&nonce_bytes[..8])?;
+
+        // Decode the bits of the commitment, and ensure the padding bits are zero.
+        let mut commitment_bits = Vec::with_capacity(num_commitment_fields * size_in_data_bits);
+        for field in &fields[2..] {
+            let bits = field.to_bits_le();
+            ensure!(bits[size_in_data_bits..].iter().all(|bit| !bit), "The commitment field exceeds the data size");
+            commitment_bits.extend_from_slice(&bits[..size_in_data_bits]);
+        }
+        ensure!(commitment_bits[num_commitment_bits..].iter().all(|bit| !bit), "The commitment padding is nonzero");
+        let commitment_bytes = snarkvm_utilities::bytes_from_bits_le(&commitment_bits[..num_commitment_bits]);
+        let commitment = PuzzleCommitment::read_le(&commitment_bytes[..])?;
+
+        // Ensure the partial solution is well-formed.
+        let partial_solution = Self::new(address, nonce, commitment);
+        partial_solution.validate()?;
+        Ok(partial_solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_fingerprint() -> Result<()> {
+        let mut rng = TestRng::default();
+        let mut fingerprints = std::collections::HashSet::new();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution.
+            let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+            let fingerprint = expected.fingerprint()?;
+
+            // Ensure the fingerprint is stable across serialization.
+            let candidate = PartialSolution::<CurrentNetwork>::read_le(&expected.to_bytes_le()?[..])?;
+            assert_eq!(fingerprint, candidate.fingerprint()?);
+
+            // Ensure a solution with a different nonce has a different fingerprint.
+            let other = PartialSolution::new(address, expected.nonce().wrapping_add(1), expected.commitment());
+            assert_ne!(fingerprint, other.fingerprint()?);
+
+            // Ensure distinct solutions have distinct fingerprints.
+            assert!(fingerprints.insert(fingerprint));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_id() -> Result<()> {
+        let mut rng = TestRng::default();
+        let mut ids = std::collections::HashSet::new();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution.
+            let nonce = u64::rand(&mut rng);
+            let commitment = KZGCommitment(rng.gen());
+            let expected = PartialSolution::new(address, nonce, commitment);
+            let id = expected.id()?;
+
+            // Ensure the ID is deterministic across constructions, and stable across serialization.
+            assert_eq!(id, PartialSolution::new(address, nonce, commitment).id()?);
+            let candidate = PartialSolution::<CurrentNetwork>::read_le(&expected.to_bytes_le()?[..])?;
+            assert_eq!(id, candidate.id()?);
+
+            // Ensure the ID changes if any field changes.
+            let other_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            assert_ne!(id, PartialSolution::new(other_address, nonce, commitment).id()?);
+            assert_ne!(id, PartialSolution::new(address, nonce.wrapping_add(1), commitment).id()?);
+            assert_ne!(id, PartialSolution::new(address, nonce, KZGCommitment(rng.gen())).id()?);
+
+            // Ensure distinct solutions have distinct IDs.
+            assert!(ids.insert(id));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_difficulty() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure the difficulty is monotonic in the target, and guards against a zero target.
+        let targets = [0, 1, 2, 999, 1000, 1_500_000, u32::MAX as u64, u64::MAX / 2, u64::MAX - 1, u64::MAX];
+        let difficulties = targets.map(PartialSolution::<CurrentNetwork>::difficulty_from_target);
+        assert!(difficulties.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(1.0, difficulties[0]);
+        assert_eq!(1.0, difficulties[1]);
+        assert_eq!(u64::MAX as f64, difficulties[9]);
+
+        // Ensure the difficulty is formatted with an SI suffix.
+        let format = PartialSolution::<CurrentNetwork>::format_difficulty;
+        assert_eq!("1.00", format(1.0));
+        assert_eq!("999.00", format(999.0));
+        assert_eq!("1.00K", format(1000.0));
+        assert_eq!("1.50M", format(1_500_000.0));
+        assert_eq!("4.29G", format(u32::MAX as f64));
+        assert_eq!("18.45E", format(u64::MAX as f64));
+
+        // Ensure the difficulty of a solution matches its target.
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+            let solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+            assert_eq!(solution.to_target()? as f64, solution.difficulty()?);
+            assert_eq!(format(solution.difficulty()?), solution.difficulty_string()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Ensure a partial solution with a valid address passes.
+            let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+            assert!(expected.validate().is_ok());
+            assert_eq!(expected, PartialSolution::read_le(&expected.to_bytes_le()?[..])?);
+
+            // Ensure a partial solution with an off-curve address fails.
+            let (x, y) = address.to_xy_coordinates();
+            let invalid_address = Address::new(Group::from_xy_coordinates_unchecked(x, y + Field::one()));
+            let candidate = PartialSolution::new(invalid_address, expected.nonce(), expected.commitment());
+            assert!(candidate.validate().is_err());
+        }
+
+        // Ensure a partial solution with a zero address fails.
+        let zero_address = Address::new(Group::zero());
+        let candidate = PartialSolution::new(zero_address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+        assert!(candidate.validate().is_err());
+
+        // Ensure an all-zero address is rejected when reading from bytes.
+        let mut bytes = candidate.to_bytes_le()?;
+        bytes[1..33].fill(0);
+        assert!(PartialSolution::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        assert!(PartialSolution::<CurrentNetwork>::from_str(&candidate.to_string()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_accumulate_shares() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the prover addresses.
+        let addresses = (0..3)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Sample solutions, with `i + 1` shares for the `i`-th address.
+        let mut solutions = Vec::new();
+        for (i, address) in addresses.iter().enumerate() {
+            for _ in 0..=i {
+                solutions.push(PartialSolution::new(*address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+            }
+        }
+        let shares = PartialSolution::accumulate_shares(&solutions);
+        assert_eq!(shares.len(), addresses.len());
+        for (i, address) in addresses.iter().enumerate() {
+            assert_eq!(shares[address], i as u64 + 1);
+            assert_eq!(solutions.iter().filter(|s| s.payout_address() == *address).count(), i + 1);
+        }
+
+        // Ensure a duplicate commitment from the same address is not counted twice.
+        solutions.push(solutions[0]);
+        // Ensure a duplicate commitment from another address is credited to the first address.
+        solutions.push(PartialSolution::new(addresses[2], u64::rand(&mut rng), solutions[1].commitment()));
+        let candidate = PartialSolution::accumulate_shares(&solutions);
+        assert_eq!(shares, candidate);
+
+        // Ensure no solutions yield no shares.
+        assert!(PartialSolution::<CurrentNetwork>::accumulate_shares(&[]).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bucket_by_epoch() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure no solutions yield no buckets.
+        assert!(PartialSolution::<CurrentNetwork>::bucket_by_epoch(vec![]).is_empty());
+
+        // Sample solutions spanning three epochs, in an interleaved order.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let epoch_numbers = [7u32, 3, 5, 3, 7, 5, 7];
+        let solutions = epoch_numbers
+            .iter()
+            .map(|epoch_number| {
+                (*epoch_number, PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())))
+            })
+            .collect::<Vec<_>>();
+
+        let buckets = PartialSolution::bucket_by_epoch(solutions.clone());
+        // Ensure the epochs are in ascending order, and the most recent epoch is last.
+        assert_eq!(buckets.keys().copied().collect::<Vec<_>>(), vec![3, 5, 7]);
+        assert_eq!(buckets.keys().next_back(), Some(&7));
+        // Ensure each bucket contains the solutions of its epoch, in their given order.
+        for (epoch_number, bucket) in &buckets {
+            let expected = solutions
+                .iter()
+                .filter(|(candidate, _)| candidate == epoch_number)
+                .map(|(_, solution)| *solution)
+                .collect::<Vec<_>>();
+            assert_eq!(&expected, bucket);
+        }
+        assert_eq!(solutions.len(), buckets.values().map(Vec::len).sum::<usize>());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_resubmission_of() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution.
+            let solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+
+            // Ensure an identical solution is a resubmission.
+            assert!(solution.is_resubmission_of(&solution));
+
+            // Ensure the same commitment with a different nonce is a resubmission, though not equal.
+            let candidate = PartialSolution::new(address, solution.nonce().wrapping_add(1), solution.commitment());
+            assert!(candidate.is_resubmission_of(&solution));
+            assert_ne!(candidate, solution);
+
+            // Ensure a different commitment is not a resubmission.
+            let candidate = PartialSolution::new(address, solution.nonce(), KZGCommitment(rng.gen()));
+            assert!(!candidate.is_resubmission_of(&solution));
+
+            // Ensure a different address is not a resubmission.
+            let other_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            let candidate = PartialSolution::new(other_address, solution.nonce(), solution.commitment());
+            assert!(!candidate.is_resubmission_of(&solution));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_eq() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution.
+            let solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+
+            // Ensure a logically-equal solution is also equal byte-for-byte.
+            let candidate = PartialSolution::new(solution.address(), solution.nonce(), solution.commitment());
+            assert_eq!(candidate, solution);
+            assert!(candidate.bytes_eq(&solution)?);
+
+            // Ensure a solution with a different nonce is not equal byte-for-byte.
+            let candidate = PartialSolution::new(address, solution.nonce().wrapping_add(1), solution.commitment());
+            assert!(!candidate.bytes_eq(&solution)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_and_verify_signature() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution, and sign it.
+            let solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+            let signature = solution.sign(&private_key, &mut rng)?;
+
+            // Ensure a valid signature verifies.
+            assert!(solution.verify_signature(&signature, &address));
+
+            // Ensure a tampered solution fails.
+            let tampered = PartialSolution::new(address, solution.nonce().wrapping_add(1), solution.commitment());
+            assert!(!tampered.verify_signature(&signature, &address));
+
+            // Ensure a signature under the wrong key fails.
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let other_address = Address::try_from(other_private_key)?;
+            assert!(!solution.verify_signature(&signature, &other_address));
+            let other_signature = solution.sign(&other_private_key, &mut rng)?;
+            assert!(!solution.verify_signature(&other_signature, &address));
+            assert!(solution.verify_signature(&other_signature, &other_address));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_fields_and_from_fields() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution.
+            let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+
+            // Ensure the field encoding round-trips.
+            let fields = expected.to_fields()?;
+            assert_eq!(4, fields.len());
+            assert_eq!(address.to_x_coordinate(), fields[0]);
+            assert_eq!(Field::from_u64(expected.nonce()), fields[1]);
+            assert_eq!(expected, PartialSolution::from_fields(&fields)?);
+
+            // Ensure a malformed field encoding is rejected.
+            assert!(PartialSolution::<CurrentNetwork>::from_fields(&fields[..3]).is_err());
+            assert!(
+                PartialSolution::<CurrentNetwork>::from_fields(&[fields.clone(), vec![Field::zero()]].concat())
+                    .is_err()
+            );
+            let mut candidate = fields.clone();
+            candidate[1] = Field::from_u64(u64::MAX) + Field::one();
+            assert!(PartialSolution::<CurrentNetwork>::from_fields(&candidate).is_err());
+            let mut candidate = fields.clone();
+            candidate[3] = -Field::one();
+            assert!(PartialSolution::<CurrentNetwork>::from_fields(&candidate).is_err());
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The protobuf message for a partial solution, as defined in `solution.proto`:
+/// ```text
+/// message SolutionProto {
+///     bytes address = 1;
+///     uint64 nonce = 2;
+///     bytes commitment = 3;
+/// }
+/// ```
+/// where the address and commitment are in their little-endian byte representations.
+///
+/// The message is encoded and decoded with `prost::Message` (e.g. `encode_to_vec` and `decode`).
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct SolutionProto {
+    /// The address of the prover.
+    #[prost(bytes = "vec", tag = "1")]
+    pub address: Vec<u8>,
+    /// The nonce for the solution.
+    #[prost(uint64, tag = "2")]
+    pub nonce: u64,
+    /// The commitment for the solution.
+    #[prost(bytes = "vec", tag = "3")]
+    pub commitment: Vec<u8>,
+}
+
+impl<N: Network> PartialSolution<N> {
+    /// Returns the partial solution as a protobuf message.
+    pub fn to_proto(&self) -> Result<SolutionProto> {
+        Ok(SolutionProto {
+            address: self.address.to_bytes_le()?,
+            nonce: self.nonce,
+            commitment: self.commitment.to_bytes_le()?,
+        })
+    }
+
+    /// Initializes the partial solution from a protobuf message.
+    ///
+    /// Returns an error if the address or commitment are not the exact byte representation of a valid value.
+    pub fn try_from_proto(proto: SolutionProto) -> Result<Self> {
+        // Read the address, and ensure there are no trailing bytes.
+        let mut reader = &proto.address[..];
+        let address = Address::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "Invalid address in the solution protobuf ({} trailing bytes)", reader.len());
+        // Read the commitment, and ensure there are no trailing bytes.
+        let mut reader = &proto.commitment[..];
+        let commitment = KZGCommitment::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "Invalid commitment in the solution protobuf ({} trailing bytes)", reader.len());
+
+        // Ensure the partial solution is well-formed.
+        let partial_solution = Self::new(address, proto.nonce, commitment);
+        partial_solution.validate()?;
+        Ok(partial_solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+    use prost::Message;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_proto() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution.
+            let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+
+            // Ensure the partial solution round trips through the protobuf message.
+            let proto = expected.to_proto()?;
+            assert_eq!(expected.address().to_bytes_le()?, proto.address);
+            assert_eq!(expected.nonce(), proto.nonce);
+            assert_eq!(expected.commitment().to_bytes_le()?, proto.commitment);
+            assert_eq!(expected, PartialSolution::try_from_proto(proto.clone())?);
+
+            // Ensure the protobuf message round trips through its encoding.
+            let bytes = proto.encode_to_vec();
+            assert_eq!(proto, SolutionProto::decode(&bytes[..])?);
+            assert_eq!(expected, PartialSolution::try_from_proto(SolutionProto::decode(&bytes[..])?)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_proto_fixtures() -> Result<()> {
+        // The encodings of the messages, as produced by a reference protobuf encoder for `solution.proto`.
+        let fixtures = [
+            (
+                SolutionProto {
+                    address: (0..32).collect(),
+                    nonce: 0x0123_4567_89ab_cdef,
+                    commitment: (100..148).collect(),
+                },
+                "0a20000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f10ef9bafcdf8acd191011a30\
+                 6465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f90919293",
+            ),
+            (SolutionProto { address: vec![1, 2], nonce: 300, commitment: vec![7] }, "0a02010210ac021a0107"),
+            (SolutionProto { address: vec![], nonce: u64::MAX, commitment: vec![] }, "10ffffffffffffffffff01"),
+            (SolutionProto::default(), ""),
+        ];
+        for (expected, expected_hex) in fixtures {
+            let expected_bytes = hex::decode(expected_hex)?;
+            assert_eq!(expected_bytes, expected.encode_to_vec());
+            assert_eq!(expected, SolutionProto::decode(&expected_bytes[..])?);
+        }
+
+        // Ensure the fields may be reordered, and unknown fields are skipped.
+        let bytes = [
+            0x10, 0xac, 0x02, // nonce = 300
+            0x20, 0x01, // unknown varint field 4
+            0x1a, 0x01, 0x07, // commitment = [7]
+            0x0a, 0x02, 0x01, 0x02, // address = [1, 2]
+            0x2d, 0x00, 0x00, 0x00, 0x00, // unknown fixed32 field 5
+        ];
+        let expected = SolutionProto { address: vec![1, 2], nonce: 300, commitment: vec![7] };
+        assert_eq!(expected, SolutionProto::decode(&bytes[..])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_proto_malformed() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+        let bytes = expected.to_proto()?.encode_to_vec();
+
+        // Ensure a truncated encoding is rejected.
+        for length in [1, bytes.len() / 2, bytes.len() - 1] {
+            assert!(SolutionProto::decode(&bytes[..length]).is_err());
+        }
+        // Ensure an overflowing varint is rejected.
+        let bytes = [0x10, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+        assert!(SolutionProto::decode(&bytes[..]).is_err());
+        // Ensure field number 0, and a mismatched wire type are rejected.
+        assert!(SolutionProto::decode(&[0x00, 0x00][..]).is_err());
+        assert!(SolutionProto::decode(&[0x08, 0x00][..]).is_err());
+
+        // Ensure a message with a malformed address or commitment is rejected.
+        let mut proto = expected.to_proto()?;
+        proto.address.push(0);
+        assert!(PartialSolution::<CurrentNetwork>::try_from_proto(proto).is_err());
+        let mut proto = expected.to_proto()?;
+        proto.commitment.pop();
+        assert!(PartialSolution::<CurrentNetwork>::try_from_proto(proto).is_err());
+        let mut proto = expected.to_proto()?;
+        proto.address.clear();
+        assert!(PartialSolution::<CurrentNetwork>::try_from_proto(proto).is_err());
+        Ok(())
+    }
+}
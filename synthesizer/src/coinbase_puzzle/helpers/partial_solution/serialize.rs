@@ -38,12 +38,15 @@ impl<'de, N: Network> Deserialize<'de> for PartialSolution<N> {
         match deserializer.is_human_readable() {
             true => {
                 let mut partial_prover_solution = serde_json::Value::deserialize(deserializer)?;
-                Ok(Self::new(
+                let partial_solution = Self::new(
                     serde_json::from_value(partial_prover_solution["address"].take()).map_err(de::Error::custom)?,
                     serde_json::from_value(partial_prover_solution["nonce"].take()).map_err(de::Error::custom)?,
                     serde_json::from_value::<PuzzleCommitment<N>>(partial_prover_solution["commitment"].take())
                         .map_err(de::Error::custom)?,
-                ))
+                );
+                // Ensure the partial solution is well-formed.
+                partial_solution.validate().map_err(de::Error::custom)?;
+                Ok(partial_solution)
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "partial solution"),
         }
@@ -16,6 +16,9 @@
 
 use super::*;
 
+/// The human-readable part of the compact string representation of a partial solution.
+const PARTIAL_SOLUTION_HRP: &str = "solution";
+
 impl<N: Network> FromStr for PartialSolution<N> {
     type Err = Error;
 
@@ -39,6 +42,61 @@ impl<N: Network> Display for PartialSolution<N> {
     }
 }
 
+impl<N: Network> PartialSolution<N> {
+    /// Returns the partial solution as a pretty-printed JSON-string.
+    ///
+    /// Note: `Display` prints the partial solution as a compact JSON-string,
+    /// and `FromStr` accepts both representations.
+    pub fn to_string_pretty(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Returns the given partial solutions as a JSON array.
+    pub fn vec_to_json(solutions: &[Self]) -> Result<String> {
+        Ok(serde_json::to_string(solutions)?)
+    }
+
+    /// Initializes the partial solutions from a JSON array, as written by `PartialSolution::vec_to_json`.
+    ///
+    /// If a partial solution is malformed, the error reports its index in the array.
+    pub fn vec_from_json(solutions: &str) -> Result<Vec<Self>> {
+        // Parse the JSON array, deferring the parsing of each partial solution.
+        let values: Vec<serde_json::Value> = serde_json::from_str(solutions)?;
+        // Parse each partial solution, reporting the index of a malformed partial solution.
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                serde_json::from_value(value)
+                    .map_err(|error| anyhow!("Invalid partial solution at index {index}: {error}"))
+            })
+            .collect()
+    }
+
+    /// Returns the partial solution as a compact bech32m string, over its byte representation.
+    ///
+    /// Unlike the JSON representation of `Display`, the compact string contains a checksum,
+    /// so that a corrupted string is rejected by `PartialSolution::from_compact_string`.
+    pub fn to_compact_string(&self) -> Result<String> {
+        Ok(bech32::encode(PARTIAL_SOLUTION_HRP, self.to_bytes_le()?.to_base32(), bech32::Variant::Bech32m)?)
+    }
+
+    /// Initializes the partial solution from a compact bech32m string, as written by `to_compact_string`.
+    pub fn from_compact_string(string: &str) -> Result<Self> {
+        // Decode the string, which verifies the checksum.
+        let (hrp, data, variant) = bech32::decode(string)?;
+        ensure!(hrp == PARTIAL_SOLUTION_HRP, "Invalid prefix for a compact partial solution: {hrp}");
+        ensure!(variant == bech32::Variant::Bech32m, "The compact partial solution is not a bech32m string");
+
+        // Read the partial solution, and ensure there are no trailing bytes.
+        let bytes = Vec::<u8>::from_base32(&data)?;
+        let mut reader = &bytes[..];
+        let partial_solution = Self::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "The compact partial solution contains {} trailing bytes", reader.len());
+        Ok(partial_solution)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,4 +119,109 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_string_pretty() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample a new partial solution.
+        let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+
+        // Check the pretty string representation.
+        let candidate = expected.to_string_pretty()?;
+        assert!(candidate.contains('\n'));
+        assert_eq!(expected, PartialSolution::from_str(&candidate)?);
+
+        // Ensure the display representation remains compact.
+        let candidate = format!("{expected}");
+        assert!(!candidate.contains('\n'));
+        assert_eq!(expected, PartialSolution::from_str(&candidate)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vec_json() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample new partial solutions.
+        let expected = (0..10)
+            .map(|_| PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())))
+            .collect::<Vec<_>>();
+
+        // Check the JSON array representation.
+        let candidate = PartialSolution::vec_to_json(&expected)?;
+        assert_eq!(expected, PartialSolution::<CurrentNetwork>::vec_from_json(&candidate)?);
+        assert_eq!(candidate, format!("[{}]", expected.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(",")));
+
+        // Check the empty JSON array.
+        assert!(PartialSolution::<CurrentNetwork>::vec_from_json(&PartialSolution::<CurrentNetwork>::vec_to_json(
+            &[]
+        )?)?
+        .is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vec_json_malformed_index() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample new partial solutions, and corrupt the nonce of the third partial solution.
+        let solutions = (0..5)
+            .map(|_| PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())))
+            .collect::<Vec<_>>();
+        let mut values = serde_json::from_str::<Vec<serde_json::Value>>(&PartialSolution::vec_to_json(&solutions)?)?;
+        values[2]["nonce"] = serde_json::Value::from("invalid");
+        let candidate = serde_json::to_string(&values)?;
+
+        // Ensure the error reports the index of the malformed partial solution.
+        let error = PartialSolution::<CurrentNetwork>::vec_from_json(&candidate).unwrap_err();
+        assert!(error.to_string().contains("index 2"));
+
+        // Ensure a malformed JSON array is rejected.
+        assert!(PartialSolution::<CurrentNetwork>::vec_from_json("{}").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_string() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample a new partial solution.
+        let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+
+        // Check the compact string representation.
+        let candidate = expected.to_compact_string()?;
+        assert!(candidate.starts_with("solution1"));
+        assert_eq!(expected, PartialSolution::from_compact_string(&candidate)?);
+        assert!(candidate.len() < expected.to_string().len());
+
+        // Ensure each single-character mutation of the data is rejected.
+        let data_start = "solution1".len();
+        for index in data_start..candidate.len() {
+            let mut mutated = candidate.clone().into_bytes();
+            mutated[index] = if mutated[index] == b'q' { b'p' } else { b'q' };
+            let mutated = String::from_utf8(mutated)?;
+            assert!(PartialSolution::<CurrentNetwork>::from_compact_string(&mutated).is_err());
+        }
+
+        // Ensure a different prefix, and a bech32 (rather than bech32m) string, are rejected.
+        let bytes = expected.to_bytes_le()?.to_base32();
+        let candidate = bech32::encode("other", bytes.clone(), bech32::Variant::Bech32m)?;
+        assert!(PartialSolution::<CurrentNetwork>::from_compact_string(&candidate).is_err());
+        let candidate = bech32::encode(PARTIAL_SOLUTION_HRP, bytes, bech32::Variant::Bech32)?;
+        assert!(PartialSolution::<CurrentNetwork>::from_compact_string(&candidate).is_err());
+
+        Ok(())
+    }
 }
@@ -15,11 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
 
 impl<N: Network> FromBytes for ProverSolution<N> {
     /// Reads the prover solution from the buffer.
+    ///
+    /// Note: The partial solution is read without the version, to keep the byte representation unchanged.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let partial_solution: PartialSolution<N> = FromBytes::read_le(&mut reader)?;
+        let partial_solution = PartialSolution::read_le_v1(&mut reader)?;
         let proof = KZGProof::read_le(&mut reader)?;
 
         Ok(Self::new(partial_solution, proof))
@@ -28,12 +31,58 @@ impl<N: Network> FromBytes for ProverSolution<N> {
 
 impl<N: Network> ToBytes for ProverSolution<N> {
     /// Writes the prover solution to the buffer.
+    ///
+    /// Note: The partial solution is written without the version, to keep the byte representation unchanged.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        self.partial_solution.write_le(&mut writer)?;
+        self.partial_solution.write_le_v1(&mut writer)?;
         self.proof.write_le(&mut writer)
     }
 }
 
+impl<N: Network> ProverSolution<N> {
+    /// Returns the prover solution as `( compression_flag || partial_solution || proof )`,
+    /// where the proof is encoded with the given compression, and the flag is `0` if compressed and `1` otherwise.
+    pub fn to_bytes_with_compression(&self, compress: Compress) -> Result<Vec<u8>> {
+        // Write the compression flag.
+        let mut bytes = vec![match compress {
+            Compress::Yes => 0u8,
+            Compress::No => 1u8,
+        }];
+        // Write the partial solution and the proof.
+        self.partial_solution.write_le_v1(&mut bytes)?;
+        self.proof.serialize_with_mode(&mut bytes, compress)?;
+        Ok(bytes)
+    }
+
+    /// Returns the prover solution as bytes, with a compressed proof.
+    pub fn to_bytes_compressed(&self) -> Result<Vec<u8>> {
+        self.to_bytes_with_compression(Compress::Yes)
+    }
+
+    /// Returns the prover solution as bytes, with an uncompressed proof.
+    pub fn to_bytes_uncompressed(&self) -> Result<Vec<u8>> {
+        self.to_bytes_with_compression(Compress::No)
+    }
+
+    /// Reads the prover solution from bytes written by `to_bytes_with_compression`,
+    /// using the leading flag to determine the compression of the proof.
+    pub fn from_bytes_with_compression(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        // Read the compression flag.
+        let compress = match u8::read_le(&mut reader)? {
+            0 => Compress::Yes,
+            1 => Compress::No,
+            flag => bail!("Invalid compression flag ({flag}) for the prover solution"),
+        };
+        // Read the partial solution and the proof.
+        let partial_solution = PartialSolution::read_le_v1(&mut reader)?;
+        let proof = PuzzleProof::<N>::deserialize_with_mode(&mut reader, compress, Validate::Yes)?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes in the prover solution", reader.len());
+
+        Ok(Self::new(partial_solution, proof))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,4 +107,73 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_legacy() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..100 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new prover solution.
+            let partial_solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+            let expected = ProverSolution::new(partial_solution, KZGProof { w: rng.gen(), random_v: None });
+
+            // Write the prover solution in the legacy layout, without the version of the partial solution.
+            let mut legacy_bytes = partial_solution.to_bytes_le()?[1..].to_vec();
+            legacy_bytes.extend_from_slice(&expected.proof().to_bytes_le()?);
+
+            // Ensure the byte representation is unchanged from the legacy layout.
+            assert_eq!(legacy_bytes, expected.to_bytes_le()?);
+            // Ensure the legacy layout is decodable, from a reader and from bytes.
+            let mut reader = &legacy_bytes[..];
+            assert_eq!(expected, ProverSolution::read_le(&mut reader)?);
+            assert!(reader.is_empty());
+            assert_eq!(expected, ProverSolution::from_bytes_le(&legacy_bytes)?);
+            // Ensure a truncated layout is rejected.
+            assert!(ProverSolution::<CurrentNetwork>::from_bytes_le(&legacy_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_with_compression() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample a new prover solution.
+        let partial_solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+        let expected = ProverSolution::new(partial_solution, KZGProof { w: rng.gen(), random_v: None });
+
+        // Check the compressed representation.
+        let compressed = expected.to_bytes_compressed()?;
+        assert_eq!(0, compressed[0]);
+        assert_eq!(expected, ProverSolution::from_bytes_with_compression(&compressed)?);
+
+        // Check the uncompressed representation.
+        let uncompressed = expected.to_bytes_uncompressed()?;
+        assert_eq!(1, uncompressed[0]);
+        assert_eq!(expected, ProverSolution::from_bytes_with_compression(&uncompressed)?);
+
+        // Ensure the compressed representation is smaller.
+        assert!(compressed.len() < uncompressed.len());
+        // Ensure the compressed representation matches `to_bytes_le`, after the flag.
+        assert_eq!(expected.to_bytes_le()?, compressed[1..]);
+
+        // Ensure an invalid flag, a mismatched flag, truncation, and trailing bytes are rejected.
+        let mut candidate = compressed.clone();
+        candidate[0] = 2;
+        assert!(ProverSolution::<CurrentNetwork>::from_bytes_with_compression(&candidate).is_err());
+        candidate[0] = 1;
+        assert!(ProverSolution::<CurrentNetwork>::from_bytes_with_compression(&candidate).is_err());
+        assert!(
+            ProverSolution::<CurrentNetwork>::from_bytes_with_compression(&compressed[..compressed.len() - 1]).is_err()
+        );
+        let candidate = [uncompressed, vec![0u8]].concat();
+        assert!(ProverSolution::<CurrentNetwork>::from_bytes_with_compression(&candidate).is_err());
+
+        Ok(())
+    }
 }
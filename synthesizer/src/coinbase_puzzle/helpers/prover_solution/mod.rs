@@ -20,6 +20,8 @@ mod string;
 
 use super::*;
 
+use std::cmp::{Ordering, Reverse};
+
 /// The prover solution for the coinbase puzzle from a prover.
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ProverSolution<N: Network> {
@@ -42,18 +44,54 @@ impl<N: Network> ProverSolution<N> {
         epoch_challenge: &EpochChallenge<N>,
         proof_target: u64,
     ) -> Result<bool> {
+        self.verify_and_target(verifying_key, epoch_challenge, proof_target).map(|(is_valid, _)| is_valid)
+    }
+
+    /// Returns `true` if the prover solution is valid, along with the target of the prover solution.
+    pub fn verify_and_target(
+        &self,
+        verifying_key: &CoinbaseVerifyingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<(bool, u64)> {
+        // Compute the prover polynomial input prefix.
+        let input_prefix = CoinbasePuzzle::prover_polynomial_input_prefix(epoch_challenge)?;
+        self.verify_and_target_with_input_prefix(verifying_key, epoch_challenge, &input_prefix, proof_target)
+    }
+
+    /// Returns `true` if the prover solution is valid, along with the target of the prover solution,
+    /// given the prover polynomial input prefix of the epoch challenge, which may be shared across prover solutions.
+    pub(crate) fn verify_and_target_with_input_prefix(
+        &self,
+        verifying_key: &CoinbaseVerifyingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        input_prefix: &[u8; 36],
+        proof_target: u64,
+    ) -> Result<(bool, u64)> {
+        // Compute the target of the prover solution.
+        let target = self.to_target()?;
+
+        // Ensure the commitment is not the identity. The identity commits to the zero polynomial,
+        // which is never the product of a prover polynomial and the epoch polynomial, and is rejected
+        // explicitly rather than relying on the pairing check.
+        if self.commitment().is_identity() {
+            bail!("The prover solution commitment cannot be the identity");
+        }
+
         // Ensure the proof is non-hiding.
         if self.proof.is_hiding() {
-            return Ok(false);
+            return Ok((false, target));
         }
 
         // Ensure that the prover solution is greater than the proof target.
-        if self.to_target()? < proof_target {
+        if target < proof_target {
             bail!("Prover puzzle does not meet the proof target requirements.")
         }
 
         // Compute the prover polynomial.
-        let prover_polynomial = self.partial_solution.to_prover_polynomial(epoch_challenge)?;
+        let input_suffix = CoinbasePuzzle::prover_polynomial_input_suffix(self.address(), self.nonce())?;
+        let prover_polynomial =
+            CoinbasePuzzle::prover_polynomial_with_input(input_prefix, &input_suffix, epoch_challenge.degree());
 
         // Compute the challenge point.
         let challenge_point = hash_commitment(&self.commitment())?;
@@ -66,7 +104,16 @@ impl<N: Network> ProverSolution<N> {
         let claimed_value = epoch_evaluation * prover_evaluation;
 
         // Check the KZG proof.
-        Ok(KZG10::check(verifying_key, &self.commitment(), challenge_point, claimed_value, self.proof())?)
+        let is_valid = CoinbasePuzzle::verify_opening(
+            verifying_key,
+            &self.commitment(),
+            challenge_point,
+            claimed_value,
+            self.proof(),
+        )?;
+
+        // Return the verification result and the target.
+        Ok((is_valid, target))
     }
 
     /// Returns the address of the prover.
@@ -101,4 +148,80 @@ impl<N: Network> ProverSolution<N> {
     pub fn to_target(&self) -> Result<u64> {
         self.partial_solution.to_target()
     }
+
+    /// Compares the prover solutions by their priority in the mempool, where `Ordering::Less` indicates
+    /// that `self` has a higher priority than `other`.
+    ///
+    /// The prover solutions are ordered by descending target, then by the (little-endian) bytes
+    /// of the puzzle commitment, then by the bytes of the proof, and finally by the bytes of the prover solution.
+    /// As this is a total order, every node sorts the same set of prover solutions into the same order.
+    ///
+    /// Note: The target is derived from the puzzle commitment alone, and does not depend on the epoch challenge.
+    pub fn cmp_for_mempool(&self, other: &Self) -> Ordering {
+        // Note: Serializing a prover solution into a vector does not fail in practice.
+        let key = |solution: &Self| {
+            (
+                Reverse(solution.to_target().unwrap_or_default()),
+                solution.commitment().to_bytes_le().unwrap_or_default(),
+                solution.proof().to_bytes_le().unwrap_or_default(),
+                solution.to_bytes_le().unwrap_or_default(),
+            )
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    use rand::seq::SliceRandom;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_cmp_for_mempool() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample prover solutions, where several prover solutions share a puzzle commitment, and thus a target.
+        let mut solutions = vec![];
+        for _ in 0..4 {
+            let commitment = KZGCommitment(rng.gen());
+            for _ in 0..4 {
+                let partial_solution = PartialSolution::new(address, u64::rand(&mut rng), commitment);
+                solutions.push(ProverSolution::new(partial_solution, KZGProof { w: rng.gen(), random_v: None }));
+            }
+            // Add a prover solution with the same proof as the previous prover solution.
+            let partial_solution = PartialSolution::new(address, u64::rand(&mut rng), commitment);
+            solutions.push(ProverSolution::new(partial_solution, *solutions[solutions.len() - 1].proof()));
+        }
+
+        // Sort the prover solutions.
+        let mut expected = solutions.clone();
+        expected.sort_by(|a, b| a.cmp_for_mempool(b));
+
+        // Ensure the prover solutions are sorted by descending target.
+        for pair in expected.windows(2) {
+            assert!(pair[0].to_target()? >= pair[1].to_target()?);
+            assert_eq!(Ordering::Less, pair[0].cmp_for_mempool(&pair[1]));
+            assert_eq!(Ordering::Greater, pair[1].cmp_for_mempool(&pair[0]));
+        }
+        // Ensure the order is consistent with equality.
+        for solution in &solutions {
+            assert_eq!(Ordering::Equal, solution.cmp_for_mempool(solution));
+        }
+
+        // Ensure the order is independent of the input order.
+        for _ in 0..10 {
+            let mut candidate = solutions.clone();
+            candidate.shuffle(&mut rng);
+            candidate.sort_by(|a, b| a.cmp_for_mempool(b));
+            assert_eq!(expected, candidate);
+        }
+
+        Ok(())
+    }
 }
@@ -32,6 +32,11 @@ impl<N: Network> PuzzleCommitment<N> {
     pub const fn new(commitment: KZGCommitment<<N as Environment>::PairingCurve>) -> Self {
         Self { commitment }
     }
+
+    /// Returns `true` if the commitment is the identity (the point at infinity).
+    pub fn is_identity(&self) -> bool {
+        self.commitment == KZGCommitment::empty()
+    }
 }
 
 impl<N: Network> From<KZGCommitment<<N as Environment>::PairingCurve>> for PuzzleCommitment<N> {
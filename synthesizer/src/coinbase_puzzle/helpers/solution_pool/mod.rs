@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use indexmap::{map::Entry, IndexMap};
+use parking_lot::RwLock;
+use std::collections::HashSet;
+
+/// A snapshot of the statistics of a solution pool.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// The number of solutions in the pool.
+    pub num_solutions: usize,
+    /// The number of distinct prover addresses in the pool.
+    pub num_addresses: usize,
+    /// The smallest proof target in the pool, or `None` if the pool is empty.
+    pub min_target: Option<u64>,
+    /// The largest proof target in the pool, or `None` if the pool is empty.
+    pub max_target: Option<u64>,
+    /// The (lower) median proof target in the pool, or `None` if the pool is empty.
+    pub median_target: Option<u64>,
+}
+
+/// A pool of pending partial solutions, keyed by their puzzle commitments,
+/// which may be shared across threads for reporting metrics.
+pub struct SolutionPool<N: Network> {
+    /// The partial solutions in the pool, with their proof targets.
+    solutions: RwLock<IndexMap<PuzzleCommitment<N>, (PartialSolution<N>, u64)>>,
+}
+
+impl<N: Network> Default for SolutionPool<N> {
+    /// Returns an empty solution pool.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> SolutionPool<N> {
+    /// Initializes an empty solution pool.
+    pub fn new() -> Self {
+        Self { solutions: Default::default() }
+    }
+
+    /// Inserts the given partial solution into the pool, and returns `true` if it was not already present.
+    ///
+    /// If the pool already contains a partial solution with the same puzzle commitment, the pool is unchanged.
+    pub fn insert(&self, solution: PartialSolution<N>) -> Result<bool> {
+        // Compute the proof target, before acquiring the lock.
+        let target = solution.to_target()?;
+        match self.solutions.write().entry(solution.commitment()) {
+            Entry::Occupied(_) => Ok(false),
+            Entry::Vacant(entry) => {
+                entry.insert((solution, target));
+                Ok(true)
+            }
+        }
+    }
+
+    /// Removes the partial solution with the given puzzle commitment from the pool, and returns it if present.
+    pub fn remove(&self, commitment: &PuzzleCommitment<N>) -> Option<PartialSolution<N>> {
+        self.solutions.write().shift_remove(commitment).map(|(solution, _)| solution)
+    }
+
+    /// Returns `true` if the pool contains a partial solution with the given puzzle commitment.
+    pub fn contains(&self, commitment: &PuzzleCommitment<N>) -> bool {
+        self.solutions.read().contains_key(commitment)
+    }
+
+    /// Returns the number of partial solutions in the pool.
+    pub fn len(&self) -> usize {
+        self.solutions.read().len()
+    }
+
+    /// Returns `true` if the pool is empty.
+    pub fn is_empty(&self) -> bool {
+        self.solutions.read().is_empty()
+    }
+
+    /// Returns the statistics of the pool, computed under a single read lock,
+    /// so that the snapshot is consistent with concurrent insertions and removals.
+    pub fn snapshot(&self) -> PoolStats {
+        // Collect the addresses and targets under the read lock.
+        let (num_addresses, mut targets) = {
+            let solutions = self.solutions.read();
+            let addresses = solutions.values().map(|(solution, _)| solution.address()).collect::<HashSet<_>>();
+            (addresses.len(), solutions.values().map(|(_, target)| *target).collect::<Vec<_>>())
+        };
+
+        // Compute the target statistics.
+        targets.sort_unstable();
+        let median_target = match targets.is_empty() {
+            true => None,
+            false => Some(targets[(targets.len() - 1) / 2]),
+        };
+        PoolStats {
+            num_solutions: targets.len(),
+            num_addresses,
+            min_target: targets.first().copied(),
+            max_target: targets.last().copied(),
+            median_target,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+    use std::sync::Arc;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a random partial solution for the given address.
+    fn sample_solution(address: Address<CurrentNetwork>, rng: &mut TestRng) -> PartialSolution<CurrentNetwork> {
+        PartialSolution::new(address, u64::rand(rng), KZGCommitment(rng.gen()))
+    }
+
+    #[test]
+    fn test_empty_snapshot() {
+        let pool = SolutionPool::<CurrentNetwork>::new();
+        assert!(pool.is_empty());
+        assert_eq!(PoolStats::default(), pool.snapshot());
+    }
+
+    #[test]
+    fn test_insert_remove_and_snapshot() -> Result<()> {
+        let mut rng = TestRng::default();
+        let addresses = (0..3)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Insert 2 solutions for each address.
+        let pool = SolutionPool::new();
+        let mut solutions = vec![];
+        for address in addresses.iter().chain(addresses.iter()) {
+            let solution = sample_solution(*address, &mut rng);
+            assert!(pool.insert(solution)?);
+            solutions.push(solution);
+        }
+        // Ensure a duplicate is not inserted again.
+        assert!(!pool.insert(solutions[0])?);
+        assert_eq!(6, pool.len());
+
+        // Ensure the snapshot matches the targets of the solutions.
+        let mut targets = solutions.iter().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+        targets.sort_unstable();
+        let expected = PoolStats {
+            num_solutions: 6,
+            num_addresses: 3,
+            min_target: Some(targets[0]),
+            max_target: Some(targets[5]),
+            median_target: Some(targets[2]),
+        };
+        assert_eq!(expected, pool.snapshot());
+
+        // Remove the solutions of the first address.
+        assert_eq!(Some(solutions[0]), pool.remove(&solutions[0].commitment()));
+        assert_eq!(Some(solutions[3]), pool.remove(&solutions[3].commitment()));
+        assert_eq!(None, pool.remove(&solutions[3].commitment()));
+        assert!(!pool.contains(&solutions[0].commitment()));
+        assert_eq!(4, pool.len());
+
+        let snapshot = pool.snapshot();
+        assert_eq!(4, snapshot.num_solutions);
+        assert_eq!(2, snapshot.num_addresses);
+        assert!(snapshot.min_target <= snapshot.median_target && snapshot.median_target <= snapshot.max_target);
+
+        // Ensure the pool is empty once every solution is removed.
+        for solution in &solutions {
+            pool.remove(&solution.commitment());
+        }
+        assert_eq!(PoolStats::default(), pool.snapshot());
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_duplicate_commitment() -> Result<()> {
+        let mut rng = TestRng::default();
+        let first_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let second_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Sample two solutions that share a puzzle commitment, but have different addresses.
+        let first = sample_solution(first_address, &mut rng);
+        let second = PartialSolution::new(second_address, first.nonce(), first.commitment());
+        assert_eq!(first.commitment(), second.commitment());
+        assert_ne!(first, second);
+
+        // Ensure the second solution is not inserted, and does not overwrite the first solution.
+        let pool = SolutionPool::new();
+        assert!(pool.insert(first)?);
+        assert!(!pool.insert(second)?);
+        assert_eq!(1, pool.len());
+        assert_eq!(1, pool.snapshot().num_addresses);
+        assert_eq!(Some(first), pool.remove(&first.commitment()));
+        assert!(pool.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_concurrent_snapshot() -> Result<()> {
+        let mut rng = TestRng::default();
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let solutions = (0..100).map(|_| sample_solution(address, &mut rng)).collect::<Vec<_>>();
+
+        let pool = Arc::new(SolutionPool::new());
+        let writer = {
+            let pool = pool.clone();
+            std::thread::spawn(move || {
+                for solution in solutions {
+                    pool.insert(solution).unwrap();
+                }
+            })
+        };
+        // Ensure each snapshot is internally consistent while solutions are inserted.
+        for _ in 0..100 {
+            let snapshot = pool.snapshot();
+            assert_eq!(snapshot.num_solutions == 0, snapshot.min_target.is_none());
+            assert!(snapshot.num_addresses <= 1);
+        }
+        writer.join().unwrap();
+        assert_eq!(100, pool.snapshot().num_solutions);
+        Ok(())
+    }
+}
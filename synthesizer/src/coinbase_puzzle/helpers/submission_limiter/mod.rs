@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// The token bucket of a prover address.
+#[derive(Copy, Clone, Debug)]
+struct TokenBucket {
+    /// The number of available tokens.
+    tokens: f64,
+    /// The time at which the tokens were last replenished.
+    last_update: Instant,
+}
+
+/// A per-address token-bucket rate limiter, for throttling the submission of solutions.
+///
+/// Each address is allotted a bucket of `burst` tokens, which is replenished at `rate` tokens per `period`.
+/// A solution is accepted if the bucket of its address has a token available, which it then consumes.
+#[derive(Clone)]
+pub struct SubmissionLimiter<N: Network> {
+    /// The number of tokens replenished per second.
+    tokens_per_second: f64,
+    /// The maximum number of tokens in a bucket.
+    burst: u32,
+    /// The token bucket for each address.
+    buckets: HashMap<Address<N>, TokenBucket>,
+}
+
+impl<N: Network> SubmissionLimiter<N> {
+    /// Initializes a new submission limiter, which accepts `rate` solutions per `period` for each address,
+    /// and up to `burst` solutions at once.
+    pub fn new(rate: u32, period: Duration, burst: u32) -> Result<Self> {
+        ensure!(rate > 0, "The submission rate must be nonzero");
+        ensure!(!period.is_zero(), "The submission period must be nonzero");
+        ensure!(burst > 0, "The submission burst must be nonzero");
+        Ok(Self { tokens_per_second: rate as f64 / period.as_secs_f64(), burst, buckets: HashMap::new() })
+    }
+
+    /// Returns the number of solutions accepted per second, for each address.
+    pub const fn tokens_per_second(&self) -> f64 {
+        self.tokens_per_second
+    }
+
+    /// Returns the maximum number of solutions accepted at once, for each address.
+    pub const fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Returns `true` if the given solution is accepted at time `now`, which consumes a token
+    /// from the bucket of its address. Otherwise, returns `false`, as the address has exceeded its rate.
+    pub fn try_accept(&mut self, solution: &PartialSolution<N>, now: Instant) -> bool {
+        let burst = self.burst as f64;
+        let tokens_per_second = self.tokens_per_second;
+
+        // Retrieve the bucket for the address, which starts full.
+        let bucket = self.buckets.entry(solution.address()).or_insert(TokenBucket { tokens: burst, last_update: now });
+
+        // Replenish the tokens for the time elapsed since the last update.
+        // Note: An earlier `now` than the last update does not replenish (or deplete) any tokens.
+        if now > bucket.last_update {
+            let elapsed = now.duration_since(bucket.last_update).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * tokens_per_second).min(burst);
+            bucket.last_update = now;
+        }
+
+        // Consume a token, if one is available.
+        match bucket.tokens >= 1.0 {
+            true => {
+                bucket.tokens -= 1.0;
+                true
+            }
+            false => false,
+        }
+    }
+
+    /// Removes the buckets that are full at time `now`, as they are equivalent to a new bucket.
+    pub fn prune(&mut self, now: Instant) {
+        let burst = self.burst as f64;
+        let tokens_per_second = self.tokens_per_second;
+        self.buckets.retain(|_, bucket| {
+            let elapsed = now.saturating_duration_since(bucket.last_update).as_secs_f64();
+            bucket.tokens + elapsed * tokens_per_second < burst
+        });
+    }
+
+    /// Returns the number of addresses that are tracked.
+    pub fn len(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Returns `true` if no addresses are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a random partial solution for the given address.
+    fn sample_solution(address: Address<CurrentNetwork>, rng: &mut TestRng) -> PartialSolution<CurrentNetwork> {
+        PartialSolution::new(address, u64::rand(rng), KZGCommitment(rng.gen()))
+    }
+
+    #[test]
+    fn test_try_accept() -> Result<()> {
+        let mut rng = TestRng::default();
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let other_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Accept 1 solution per second, with a burst of 3 solutions.
+        let mut limiter = SubmissionLimiter::new(1, Duration::from_secs(1), 3)?;
+        let start = Instant::now();
+
+        // Ensure the burst is accepted, and the next solution is rejected.
+        for _ in 0..3 {
+            assert!(limiter.try_accept(&sample_solution(address, &mut rng), start));
+        }
+        assert!(!limiter.try_accept(&sample_solution(address, &mut rng), start));
+        assert!(!limiter.try_accept(&sample_solution(address, &mut rng), start + Duration::from_millis(500)));
+
+        // Ensure another address is not affected.
+        assert!(limiter.try_accept(&sample_solution(other_address, &mut rng), start));
+
+        // Ensure the address recovers one token after the window.
+        let now = start + Duration::from_secs(1);
+        assert!(limiter.try_accept(&sample_solution(address, &mut rng), now));
+        assert!(!limiter.try_accept(&sample_solution(address, &mut rng), now));
+
+        // Ensure the address recovers at most the burst after a long time.
+        let now = start + Duration::from_secs(100);
+        for _ in 0..3 {
+            assert!(limiter.try_accept(&sample_solution(address, &mut rng), now));
+        }
+        assert!(!limiter.try_accept(&sample_solution(address, &mut rng), now));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prune() -> Result<()> {
+        let mut rng = TestRng::default();
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        let mut limiter = SubmissionLimiter::new(2, Duration::from_secs(1), 2)?;
+        let start = Instant::now();
+        assert!(limiter.try_accept(&sample_solution(address, &mut rng), start));
+        assert_eq!(1, limiter.len());
+
+        // Ensure a bucket that is not yet full is retained.
+        limiter.prune(start + Duration::from_millis(100));
+        assert_eq!(1, limiter.len());
+
+        // Ensure a full bucket is removed.
+        limiter.prune(start + Duration::from_secs(1));
+        assert!(limiter.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(SubmissionLimiter::<CurrentNetwork>::new(0, Duration::from_secs(1), 1).is_err());
+        assert!(SubmissionLimiter::<CurrentNetwork>::new(1, Duration::ZERO, 1).is_err());
+        assert!(SubmissionLimiter::<CurrentNetwork>::new(1, Duration::from_secs(1), 0).is_err());
+        assert!(SubmissionLimiter::<CurrentNetwork>::new(1, Duration::from_secs(1), 1).is_ok());
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+
+/// A partial solution, tagged with the number of the epoch it was computed for.
+///
+/// The tag allows a solution for an expired epoch to be rejected early, without verifying its proof.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TaggedSolution<N: Network> {
+    /// The epoch number of the solution.
+    epoch_number: u32,
+    /// The partial solution.
+    partial_solution: PartialSolution<N>,
+}
+
+impl<N: Network> TaggedSolution<N> {
+    /// Initializes a new instance of the tagged solution.
+    pub const fn new(epoch_number: u32, partial_solution: PartialSolution<N>) -> Self {
+        Self { epoch_number, partial_solution }
+    }
+
+    /// Returns the epoch number of the solution.
+    pub const fn epoch_number(&self) -> u32 {
+        self.epoch_number
+    }
+
+    /// Returns the partial solution.
+    pub const fn partial_solution(&self) -> &PartialSolution<N> {
+        &self.partial_solution
+    }
+
+    /// Returns `true` if the solution is tagged with the given current epoch number.
+    pub const fn is_current(&self, current_epoch: u32) -> bool {
+        self.epoch_number == current_epoch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_is_current() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample a new partial solution.
+        let partial_solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+        let current_epoch = rng.gen_range(1..u32::MAX);
+
+        // Ensure a solution tagged with the current epoch passes.
+        let candidate = TaggedSolution::new(current_epoch, partial_solution);
+        assert!(candidate.is_current(current_epoch));
+        assert_eq!(&partial_solution, candidate.partial_solution());
+
+        // Ensure a solution tagged with an old epoch is stale.
+        let candidate = TaggedSolution::new(current_epoch - 1, partial_solution);
+        assert!(!candidate.is_current(current_epoch));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of buckets in the target histogram, one for each bit length of a `u64` target.
+const NUM_BUCKETS: usize = u64::BITS as usize + 1;
+
+/// A histogram of proof targets, with log-scale buckets, for collecting mining statistics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TargetHistogram {
+    /// The number of targets in each bucket, where the bucket at index `i > 0`
+    /// contains the targets in `[2^(i - 1), 2^i)`, and the bucket at index `0` contains the target `0`.
+    buckets: [u64; NUM_BUCKETS],
+    /// The total number of targets.
+    count: u64,
+}
+
+impl Default for TargetHistogram {
+    /// Returns an empty target histogram.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TargetHistogram {
+    /// Initializes an empty target histogram.
+    pub const fn new() -> Self {
+        Self { buckets: [0u64; NUM_BUCKETS], count: 0 }
+    }
+
+    /// Initializes a target histogram from the targets of the given partial solutions.
+    pub fn from_solutions<N: Network>(partial_solutions: &[PartialSolution<N>]) -> Result<Self> {
+        let mut histogram = Self::new();
+        for partial_solution in partial_solutions {
+            histogram.record(partial_solution.to_target()?);
+        }
+        Ok(histogram)
+    }
+
+    /// Records the given proof target.
+    pub fn record(&mut self, target: u64) {
+        let index = Self::bucket_index(target);
+        self.buckets[index] = self.buckets[index].saturating_add(1);
+        self.count = self.count.saturating_add(1);
+    }
+
+    /// Returns the total number of recorded targets.
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the number of targets in each bucket.
+    pub const fn buckets(&self) -> &[u64; NUM_BUCKETS] {
+        &self.buckets
+    }
+
+    /// Returns the index of the bucket for the given target, which is the bit length of the target.
+    pub const fn bucket_index(target: u64) -> usize {
+        (u64::BITS - target.leading_zeros()) as usize
+    }
+
+    /// Returns the smallest target in the bucket at the given index.
+    pub const fn bucket_lower_bound(index: usize) -> u64 {
+        match index {
+            0 => 0,
+            index => 1u64 << (index - 1),
+        }
+    }
+
+    /// Returns the lower bound of the bucket containing the given percentile of the recorded targets,
+    /// where the percentile is in `[0, 100]`.
+    pub fn percentile(&self, percentile: u8) -> Result<u64> {
+        ensure!(percentile <= 100, "The percentile must be at most 100, found {percentile}");
+        ensure!(self.count > 0, "Cannot compute a percentile of an empty target histogram");
+
+        // Compute the rank of the percentile, which is at least 1.
+        let rank = ((self.count as u128 * percentile as u128 + 99) / 100).max(1);
+
+        // Find the bucket containing the rank.
+        let mut cumulative = 0u128;
+        for (index, num_targets) in self.buckets.iter().enumerate() {
+            cumulative += *num_targets as u128;
+            if cumulative >= rank {
+                return Ok(Self::bucket_lower_bound(index));
+            }
+        }
+        bail!("The target histogram is inconsistent with its count")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+    use snarkvm_algorithms::polycommit::kzg10::KZGCommitment;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_record() {
+        let mut histogram = TargetHistogram::new();
+        assert_eq!(0, histogram.count());
+        assert!(histogram.percentile(50).is_err());
+
+        for target in [0, 1, 2, 3, 4, 7, 8, u64::MAX] {
+            histogram.record(target);
+        }
+        assert_eq!(8, histogram.count());
+        assert_eq!(1, histogram.buckets()[0]);
+        assert_eq!(1, histogram.buckets()[1]);
+        assert_eq!(2, histogram.buckets()[2]);
+        assert_eq!(2, histogram.buckets()[3]);
+        assert_eq!(1, histogram.buckets()[4]);
+        assert_eq!(1, histogram.buckets()[64]);
+        assert_eq!(histogram.count(), histogram.buckets().iter().sum::<u64>());
+
+        assert_eq!(0, histogram.percentile(0).unwrap());
+        assert_eq!(2, histogram.percentile(50).unwrap());
+        assert_eq!(1u64 << 63, histogram.percentile(100).unwrap());
+        assert!(histogram.percentile(101).is_err());
+    }
+
+    #[test]
+    fn test_percentiles_are_monotonic() {
+        let mut rng = TestRng::default();
+        let mut histogram = TargetHistogram::default();
+
+        for _ in 0..ITERATIONS {
+            histogram.record(rng.gen::<u64>() >> rng.gen_range(0..64u32));
+
+            let percentiles = (0..=100).map(|percentile| histogram.percentile(percentile).unwrap()).collect::<Vec<_>>();
+            assert!(percentiles.windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+        assert_eq!(ITERATIONS, histogram.count());
+    }
+
+    #[test]
+    fn test_from_solutions() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let partial_solutions = (0..ITERATIONS)
+            .map(|_| {
+                let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+                let address = Address::try_from(private_key)?;
+                Ok(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let histogram = TargetHistogram::from_solutions(&partial_solutions)?;
+        assert_eq!(ITERATIONS, histogram.count());
+
+        // Ensure the histogram matches recording each target individually.
+        let mut expected = TargetHistogram::new();
+        for partial_solution in &partial_solutions {
+            expected.record(partial_solution.to_target()?);
+        }
+        assert_eq!(expected, histogram);
+
+        Ok(())
+    }
+}
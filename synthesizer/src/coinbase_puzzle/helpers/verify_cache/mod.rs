@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use blake2::Digest;
+use indexmap::IndexMap;
+
+/// A least-recently-used cache of coinbase solution verification results, for a single epoch challenge.
+///
+/// The results are keyed by the hash of the coinbase solution and the coinbase and proof targets.
+/// The cache is cleared whenever it is used with a different epoch challenge.
+#[derive(Clone)]
+pub struct VerifyCache<N: Network> {
+    /// The maximum number of verification results in the cache.
+    capacity: usize,
+    /// The epoch challenge of the verification results, as `(epoch_number, epoch_block_hash, degree)`.
+    epoch: Option<(u32, N::BlockHash, u32)>,
+    /// The verification results, in order from least to most recently used.
+    results: IndexMap<[u8; 32], bool>,
+    /// The number of cache hits.
+    num_hits: u64,
+    /// The number of cache misses.
+    num_misses: u64,
+}
+
+impl<N: Network> VerifyCache<N> {
+    /// Initializes a new verification cache, holding up to `capacity` verification results.
+    pub fn new(capacity: usize) -> Result<Self> {
+        ensure!(capacity > 0, "The verification cache capacity must be nonzero");
+        Ok(Self { capacity, epoch: None, results: IndexMap::with_capacity(capacity), num_hits: 0, num_misses: 0 })
+    }
+
+    /// Returns the maximum number of verification results in the cache.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of verification results in the cache.
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Returns the number of cache hits.
+    pub const fn num_hits(&self) -> u64 {
+        self.num_hits
+    }
+
+    /// Returns the number of cache misses.
+    pub const fn num_misses(&self) -> u64 {
+        self.num_misses
+    }
+
+    /// Returns the cache key for the given coinbase solution and targets.
+    pub(crate) fn to_key(
+        coinbase_solution: &CoinbaseSolution<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<[u8; 32]> {
+        let mut hasher = blake2::Blake2s256::new();
+        hasher.update(coinbase_solution.to_bytes_le()?);
+        hasher.update(coinbase_target.to_le_bytes());
+        hasher.update(proof_target.to_le_bytes());
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&hasher.finalize());
+        Ok(key)
+    }
+
+    /// Prepares the cache for the given epoch challenge, clearing it if the epoch challenge differs.
+    pub(crate) fn prepare(&mut self, epoch_challenge: &EpochChallenge<N>) {
+        let epoch = (epoch_challenge.epoch_number(), epoch_challenge.epoch_block_hash(), epoch_challenge.degree());
+        if self.epoch != Some(epoch) {
+            self.results.clear();
+            self.epoch = Some(epoch);
+        }
+    }
+
+    /// Returns the verification result for the given key, and marks it as the most recently used.
+    pub(crate) fn get(&mut self, key: &[u8; 32]) -> Option<bool> {
+        match self.results.shift_remove(key) {
+            Some(result) => {
+                self.results.insert(*key, result);
+                self.num_hits += 1;
+                Some(result)
+            }
+            None => {
+                self.num_misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Inserts the verification result for the given key, evicting the least recently used result if necessary.
+    pub(crate) fn insert(&mut self, key: [u8; 32], result: bool) {
+        self.results.shift_remove(&key);
+        if self.results.len() >= self.capacity {
+            self.results.shift_remove_index(0);
+        }
+        self.results.insert(key, result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru_eviction() -> Result<()> {
+        let mut cache = VerifyCache::<console::network::Testnet3>::new(2)?;
+
+        // Fill the cache.
+        cache.insert([0u8; 32], true);
+        cache.insert([1u8; 32], false);
+        assert_eq!(2, cache.len());
+
+        // Use the first result, so the second result is the least recently used.
+        assert_eq!(Some(true), cache.get(&[0u8; 32]));
+
+        // Ensure the least recently used result is evicted.
+        cache.insert([2u8; 32], true);
+        assert_eq!(2, cache.len());
+        assert_eq!(None, cache.get(&[1u8; 32]));
+        assert_eq!(Some(true), cache.get(&[0u8; 32]));
+        assert_eq!(Some(true), cache.get(&[2u8; 32]));
+        assert_eq!(3, cache.num_hits());
+        assert_eq!(1, cache.num_misses());
+
+        // Ensure a zero capacity is rejected.
+        assert!(VerifyCache::<console::network::Testnet3>::new(0).is_err());
+
+        Ok(())
+    }
+}
@@ -26,8 +26,9 @@ mod tests;
 use crate::UniversalSRS;
 use console::{
     account::Address,
-    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, Network, Result, ToBytes},
+    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, FromBytes, Network, Result, ToBytes},
     program::cfg_into_iter,
+    types::Field,
 };
 use snarkvm_algorithms::{
     fft::{DensePolynomial, EvaluationDomain},
@@ -36,12 +37,32 @@ use snarkvm_algorithms::{
 };
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{PrimeField, Zero};
-
-use std::sync::Arc;
+use snarkvm_utilities::CanonicalSerialize;
+
+use blake2::Digest;
+use std::{
+    cmp::Reverse,
+    collections::HashSet,
+    io::{Read, Write},
+    sync::Arc,
+};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+#[cfg(test)]
+thread_local! {
+    /// The number of prover polynomial input prefixes computed on this thread, to test that they are shared.
+    static NUM_INPUT_PREFIXES: std::cell::Cell<usize> = std::cell::Cell::new(0);
+}
+
+/// The magic bytes for the serialized coinbase puzzle keys.
+const COINBASE_KEYS_MAGIC: [u8; 4] = *b"ACPK";
+/// The version of the serialized coinbase puzzle keys.
+const COINBASE_KEYS_VERSION: u16 = 1;
+/// The size of the checksum for the serialized coinbase puzzle keys.
+const COINBASE_KEYS_CHECKSUM_SIZE: usize = 32;
+
 #[derive(Clone)]
 pub enum CoinbasePuzzle<N: Network> {
     /// The prover contains the coinbase puzzle proving key.
@@ -61,6 +82,65 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(srs)
     }
 
+    /// Returns the coinbase puzzle proving and verifying keys for the given degree,
+    /// where the SRS is derived deterministically from the given seed.
+    ///
+    /// # Warning
+    /// This method is **insecure**, and is intended for tests and local testnets only.
+    /// The trapdoor of the SRS is sampled from the seed, so anyone with the seed can forge proofs.
+    #[cfg(any(test, feature = "setup"))]
+    pub fn setup_from_seed(
+        seed: [u8; 32],
+        max_degree: usize,
+    ) -> Result<(CoinbaseProvingKey<N>, CoinbaseVerifyingKey<N>)> {
+        use rand::{rngs::StdRng, SeedableRng};
+        use snarkvm_curves::{AffineCurve, PairingCurve, ProjectiveCurve};
+        use snarkvm_fields::One;
+        use snarkvm_utilities::Uniform;
+
+        // Compute the product domain.
+        let product_domain = Self::product_domain(u32::try_from(max_degree)?)?;
+
+        // Sample the trapdoor from the seed.
+        let beta = <N::PairingCurve as PairingEngine>::Fr::rand(&mut StdRng::from_seed(seed));
+
+        // Compute the powers of beta in G1, for each element of the product domain.
+        let g = <N::PairingCurve as PairingEngine>::G1Affine::prime_subgroup_generator();
+        let mut power_of_beta = <N::PairingCurve as PairingEngine>::Fr::one();
+        let mut powers_of_beta_g = Vec::with_capacity(product_domain.size());
+        for _ in 0..product_domain.size() {
+            powers_of_beta_g.push(g * power_of_beta);
+            power_of_beta *= beta;
+        }
+        // Compute the Lagrange basis, as in `SRS::lagrange_basis`.
+        let lagrange_basis_at_beta_g =
+            <N::PairingCurve as PairingEngine>::G1Projective::batch_normalization_into_affine(
+                product_domain.ifft(&powers_of_beta_g),
+            );
+
+        // Compute the verifying key.
+        let h = <N::PairingCurve as PairingEngine>::G2Affine::prime_subgroup_generator();
+        let beta_h = (h * beta).to_affine();
+        let verifying_key = CoinbaseVerifyingKey::<N> {
+            g,
+            gamma_g: <N::PairingCurve as PairingEngine>::G1Affine::zero(), // We don't use gamma_g later on since we are not hiding.
+            h,
+            beta_h,
+            prepared_h: h.prepare(),
+            prepared_beta_h: beta_h.prepare(),
+        };
+
+        let proving_key = CoinbaseProvingKey {
+            product_domain,
+            product_domain_elements: product_domain.elements().collect(),
+            lagrange_basis_at_beta_g,
+            fft_precomputation: product_domain.precompute_fft(),
+            verifying_key: verifying_key.clone(),
+        };
+
+        Ok((proving_key, verifying_key))
+    }
+
     /// Load the coinbase puzzle proving and verifying keys.
     pub fn load() -> Result<Self> {
         let max_degree = N::COINBASE_PUZZLE_DEGREE;
@@ -103,6 +183,95 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(Self::Prover(Arc::new(pk)))
     }
 
+    /// Writes the coinbase puzzle proving and verifying keys to the given writer, as
+    /// `( magic || version || degree || lagrange_basis || verifying_key || checksum )`,
+    /// where the checksum is the BLAKE2s-256 hash of the preceding bytes.
+    pub fn save_keys<W: Write>(
+        proving_key: &CoinbaseProvingKey<N>,
+        verifying_key: &CoinbaseVerifyingKey<N>,
+        mut writer: W,
+    ) -> Result<()> {
+        // Ensure the verifying key corresponds to the proving key.
+        ensure!(
+            proving_key.verifying_key == *verifying_key,
+            "The coinbase verifying key does not match the proving key"
+        );
+
+        // Retrieve the size of the product domain.
+        let domain_size = proving_key.product_domain.size();
+        ensure!(
+            proving_key.lagrange_basis_at_beta_g.len() == domain_size,
+            "The Lagrange basis does not match the product domain size"
+        );
+        // Compute the largest degree for the product domain, which produces the same keys when trimmed.
+        let degree = u32::try_from(domain_size / 2)?
+            .checked_sub(1)
+            .ok_or_else(|| anyhow!("Invalid product domain size ({domain_size})"))?;
+
+        // Write the header.
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&COINBASE_KEYS_MAGIC);
+        COINBASE_KEYS_VERSION.write_le(&mut bytes)?;
+        degree.write_le(&mut bytes)?;
+        // Write the keys.
+        for element in &proving_key.lagrange_basis_at_beta_g {
+            element.write_le(&mut bytes)?;
+        }
+        verifying_key.write_le(&mut bytes)?;
+
+        // Append the checksum.
+        let checksum = blake2::Blake2s256::digest(&bytes);
+        bytes.extend_from_slice(&checksum);
+
+        Ok(writer.write_all(&bytes)?)
+    }
+
+    /// Reads the coinbase puzzle proving and verifying keys from the given reader,
+    /// as written by `CoinbasePuzzle::save_keys`.
+    pub fn load_keys<R: Read>(mut reader: R) -> Result<(CoinbaseProvingKey<N>, CoinbaseVerifyingKey<N>)> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        // Ensure the checksum is valid.
+        let checksum_index = match bytes.len().checked_sub(COINBASE_KEYS_CHECKSUM_SIZE) {
+            Some(checksum_index) => checksum_index,
+            None => bail!("The coinbase puzzle keys are missing the checksum"),
+        };
+        let (bytes, checksum) = bytes.split_at(checksum_index);
+        ensure!(
+            blake2::Blake2s256::digest(bytes).as_slice() == checksum,
+            "The coinbase puzzle keys are corrupt (invalid checksum)"
+        );
+
+        // Read the header.
+        let mut reader = bytes;
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        ensure!(magic == COINBASE_KEYS_MAGIC, "Invalid magic bytes for the coinbase puzzle keys");
+        let version = u16::read_le(&mut reader)?;
+        ensure!(version == COINBASE_KEYS_VERSION, "Unsupported version ({version}) for the coinbase puzzle keys");
+        let degree = u32::read_le(&mut reader)?;
+
+        // Compute the product domain.
+        let product_domain = Self::product_domain(degree)?;
+
+        // Read the keys.
+        let lagrange_basis_at_beta_g =
+            (0..product_domain.size()).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        let verifying_key = CoinbaseVerifyingKey::<N>::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes in the coinbase puzzle keys", reader.len());
+
+        let proving_key = CoinbaseProvingKey {
+            product_domain,
+            product_domain_elements: product_domain.elements().collect(),
+            lagrange_basis_at_beta_g,
+            fft_precomputation: product_domain.precompute_fft(),
+            verifying_key: verifying_key.clone(),
+        };
+
+        Ok((proving_key, verifying_key))
+    }
+
     /// Returns a prover solution to the coinbase puzzle.
     pub fn prove(
         &self,
@@ -110,6 +279,101 @@ impl<N: Network> CoinbasePuzzle<N> {
         address: Address<N>,
         nonce: u64,
         minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<N>> {
+        // Compute the prover polynomial input prefix and suffix.
+        let input_prefix = Self::prover_polynomial_input_prefix(epoch_challenge)?;
+        let input_suffix = Self::prover_polynomial_input_suffix(address, nonce)?;
+        // Compute the prover solution.
+        self.prove_internal(epoch_challenge, &input_prefix, &input_suffix, address, nonce, minimum_proof_target)
+    }
+
+    /// Returns a prover solution to the coinbase puzzle for each of the given epoch challenges,
+    /// for the same address and nonce. The results are in the same order as the epoch challenges.
+    ///
+    /// This is useful at an epoch boundary, where a prover may attempt a nonce for both the old and new epoch.
+    /// The work derived from the address and nonce is computed once, and shared across the epoch challenges.
+    pub fn prove_multi(
+        &self,
+        epoch_challenges: &[EpochChallenge<N>],
+        address: Address<N>,
+        nonce: u64,
+    ) -> Vec<Result<ProverSolution<N>>> {
+        // Compute the prover polynomial input suffix, which is shared across the epoch challenges.
+        let input_suffix = match Self::prover_polynomial_input_suffix(address, nonce) {
+            Ok(input_suffix) => input_suffix,
+            Err(error) => return epoch_challenges.iter().map(|_| Err(anyhow!("{error}"))).collect(),
+        };
+
+        // Compute the prover solution for each epoch challenge.
+        epoch_challenges
+            .iter()
+            .map(|epoch_challenge| {
+                let input_prefix = Self::prover_polynomial_input_prefix(epoch_challenge)?;
+                self.prove_internal(epoch_challenge, &input_prefix, &input_suffix, address, nonce, None)
+            })
+            .collect()
+    }
+
+    /// Returns the epoch challenge that follows the given epoch challenge, for the given block hash and degree.
+    ///
+    /// The epoch number is incremented, and the epoch polynomial is re-derived from
+    /// `( epoch_number || epoch_block_hash )`, as in `EpochChallenge::new`.
+    pub fn next_epoch_challenge(
+        previous: &EpochChallenge<N>,
+        block_hash: N::BlockHash,
+        degree: u32,
+    ) -> Result<EpochChallenge<N>> {
+        // Increment the epoch number.
+        let epoch_number = match previous.epoch_number().checked_add(1) {
+            Some(epoch_number) => epoch_number,
+            None => bail!("Cannot advance the epoch challenge beyond epoch {}", previous.epoch_number()),
+        };
+        EpochChallenge::new(epoch_number, block_hash, degree)
+    }
+
+    /// Returns the address state for the given epoch challenge and address, which may be reused across nonces.
+    pub fn prepare_address(&self, epoch_challenge: &EpochChallenge<N>, address: Address<N>) -> Result<AddressState<N>> {
+        AddressState::new(epoch_challenge, address)
+    }
+
+    /// Returns a prover solution to the coinbase puzzle, for the given (prepared) address state.
+    pub fn prove_with_address_state(
+        &self,
+        address_state: &AddressState<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<N>> {
+        self.prove_with_polynomial(
+            address_state.epoch_challenge(),
+            address_state.prover_polynomial(nonce),
+            address_state.address(),
+            nonce,
+            minimum_proof_target,
+        )
+    }
+
+    /// Returns a prover solution to the coinbase puzzle, for the given epoch challenge and input prefix and suffix.
+    fn prove_internal(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        input_prefix: &[u8; 36],
+        input_suffix: &[u8; 40],
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<N>> {
+        let polynomial = Self::prover_polynomial_with_input(input_prefix, input_suffix, epoch_challenge.degree());
+        self.prove_with_polynomial(epoch_challenge, polynomial, address, nonce, minimum_proof_target)
+    }
+
+    /// Returns a prover solution to the coinbase puzzle, for the given epoch challenge and prover polynomial.
+    fn prove_with_polynomial(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
     ) -> Result<ProverSolution<N>> {
         // Retrieve the coinbase proving key.
         let pk = match self {
@@ -117,16 +381,7 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
 
-        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
-
-        let product_evaluations = {
-            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
-            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
-                &polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            );
-            product_evaluations
-        };
+        let product_evaluations = Self::product_evaluations(pk, epoch_challenge, &polynomial);
         let (commitment, _rand) =
             KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
 
@@ -158,6 +413,20 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(ProverSolution::new(partial_solution, proof))
     }
 
+    /// Returns the evaluations of the product of the epoch polynomial and the given prover polynomial,
+    /// over the product domain, which are committed to in the Lagrange basis.
+    pub(crate) fn product_evaluations(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+    ) -> Vec<<N::PairingCurve as PairingEngine>::Fr> {
+        let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(polynomial, &pk.fft_precomputation);
+        pk.product_domain.mul_polynomials_in_evaluation_domain(
+            &polynomial_evaluations,
+            &epoch_challenge.epoch_polynomial_evaluations().evaluations,
+        )
+    }
+
     /// Returns a coinbase solution for the given epoch challenge and prover solutions.
     ///
     /// # Note
@@ -188,7 +457,7 @@ impl<N: Network> CoinbasePuzzle<N> {
         };
         ensure!(!has_duplicates(prover_solutions), "Cannot accumulate duplicate prover solutions");
 
-        let (prover_polynomials, partial_solutions): (Vec<_>, Vec<_>) = cfg_iter!(prover_solutions)
+        let mut prover_polynomials_and_partial_solutions = cfg_iter!(prover_solutions)
             .filter_map(|solution| {
                 if solution.proof().is_hiding() {
                     return None;
@@ -196,7 +465,13 @@ impl<N: Network> CoinbasePuzzle<N> {
                 let polynomial = solution.to_prover_polynomial(epoch_challenge).ok()?;
                 Some((polynomial, PartialSolution::new(solution.address(), solution.nonce(), solution.commitment())))
             })
-            .unzip();
+            .collect::<Vec<_>>();
+
+        // Sort the partial solutions into the canonical order, as the challenge points depend on the order.
+        prover_polynomials_and_partial_solutions
+            .sort_by_cached_key(|(_, partial_solution)| CoinbaseSolution::canonical_order_key(partial_solution));
+        let (prover_polynomials, partial_solutions): (Vec<_>, Vec<_>) =
+            prover_polynomials_and_partial_solutions.into_iter().unzip();
 
         // Compute the challenge points.
         let mut challenges = hash_commitments(partial_solutions.iter().map(|solution| *solution.commitment()))?;
@@ -248,6 +523,84 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(CoinbaseSolution::new(partial_solutions, proof))
     }
 
+    /// Returns a coinbase solution for the union of the given sets of prover solutions,
+    /// keeping the (at most) `max_count` prover solutions with the highest targets.
+    ///
+    /// The prover solutions are deduplicated by their puzzle commitments, and ties in the target
+    /// are broken by the canonical order. As the proof of a coinbase solution is computed over all of
+    /// its prover solutions, the sets are merged prior to accumulation.
+    ///
+    /// # Note
+    /// This method does *not* check that the prover solutions are valid.
+    pub fn merge_unchecked(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_solutions: &[ProverSolution<N>],
+        other_prover_solutions: &[ProverSolution<N>],
+        max_count: usize,
+    ) -> Result<CoinbaseSolution<N>> {
+        // Deduplicate the prover solutions by their puzzle commitments.
+        let mut commitments = HashSet::new();
+        let mut solutions = prover_solutions
+            .iter()
+            .chain(other_prover_solutions)
+            .filter(|solution| commitments.insert(solution.commitment()))
+            .map(|solution| Ok((solution.to_target()?, *solution)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Sort the prover solutions by descending target, and then by the canonical order.
+        solutions.sort_by_cached_key(|(target, solution)| {
+            let partial_solution = PartialSolution::new(solution.address(), solution.nonce(), solution.commitment());
+            (Reverse(*target), CoinbaseSolution::canonical_order_key(&partial_solution))
+        });
+
+        // Keep the prover solutions with the highest targets.
+        let solutions = solutions.into_iter().take(max_count).map(|(_, solution)| solution).collect::<Vec<_>>();
+
+        // Accumulate the prover solutions.
+        self.accumulate_unchecked(epoch_challenge, &solutions)
+    }
+
+    /// Returns a coinbase solution for the given prover solutions, keeping the prover solutions with the
+    /// highest targets such that the serialized coinbase solution is at most `max_bytes` bytes,
+    /// along with the number of (deduplicated) prover solutions that were pruned.
+    ///
+    /// As the proof of a coinbase solution is computed over all of its prover solutions, the prover solutions
+    /// are pruned prior to accumulation (see `merge_unchecked`). If not even one prover solution fits
+    /// within `max_bytes`, no coinbase solution is returned, and every prover solution is reported as pruned.
+    ///
+    /// # Note
+    /// This method does *not* check that the prover solutions are valid.
+    pub fn prune_to_size_unchecked(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_solutions: &[ProverSolution<N>],
+        max_bytes: usize,
+    ) -> Result<(Option<CoinbaseSolution<N>>, usize)> {
+        // Compute the number of distinct prover solutions.
+        let num_solutions = prover_solutions.iter().map(|solution| solution.commitment()).collect::<HashSet<_>>().len();
+
+        // Compute the size of a coinbase solution without partial solutions, as `( num_partial_solutions || proof )`.
+        // Note: The size of a (non-hiding) proof does not depend on its value.
+        let base_size = 4 + PuzzleProof::<N>::default().to_bytes_le()?.len();
+        // Compute the maximum number of partial solutions that fit within `max_bytes`.
+        let max_count = max_bytes.saturating_sub(base_size) / PARTIAL_SOLUTION_UNVERSIONED_SIZE_IN_BYTES;
+
+        // If not even one prover solution fits, every prover solution is pruned.
+        if max_count == 0 || num_solutions == 0 {
+            return Ok((None, num_solutions));
+        }
+
+        // Keep the prover solutions with the highest targets, and accumulate them.
+        let coinbase_solution = self.merge_unchecked(epoch_challenge, prover_solutions, &[], max_count)?;
+        ensure!(
+            coinbase_solution.to_bytes_le()?.len() <= max_bytes,
+            "The pruned coinbase solution exceeds {max_bytes} bytes"
+        );
+        let num_pruned = num_solutions - coinbase_solution.len();
+        Ok((Some(coinbase_solution), num_pruned))
+    }
+
     /// Returns `true` if the coinbase solution is valid.
     pub fn verify(
         &self,
@@ -285,6 +638,11 @@ impl<N: Network> CoinbasePuzzle<N> {
             bail!("The coinbase solution contains duplicate puzzle commitments");
         }
 
+        // Ensure none of the puzzle commitments are the identity, as in `ProverSolution::verify`.
+        if coinbase_solution.puzzle_commitments().any(|commitment| commitment.is_identity()) {
+            bail!("The coinbase solution contains an identity puzzle commitment");
+        }
+
         // Compute the prover polynomials.
         let prover_polynomials = cfg_iter!(coinbase_solution.partial_solutions())
             // Ensure that each of the prover solutions meets the required proof target.
@@ -332,13 +690,112 @@ impl<N: Network> CoinbasePuzzle<N> {
         };
 
         // Return the verification result.
-        Ok(KZG10::check(
+        Self::verify_opening(
             coinbase_verifying_key,
             &accumulator_commitment,
             accumulator_point,
             accumulator_evaluation,
             coinbase_solution.proof(),
-        )?)
+        )
+    }
+
+    /// Returns `true` for each of the given prover solutions that is valid, as in `ProverSolution::verify`.
+    /// The results are in the same order as the prover solutions.
+    ///
+    /// Unlike `verify` on a coinbase solution, each prover solution is checked independently, so that a single
+    /// invalid prover solution does not hide the results of the others. The work derived from the epoch challenge
+    /// alone is computed once, and shared across the prover solutions.
+    pub fn verify_many(
+        &self,
+        prover_solutions: &[ProverSolution<N>],
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Vec<Result<bool>> {
+        // Compute the prover polynomial input prefix, which is shared across the prover solutions.
+        let input_prefix = match Self::prover_polynomial_input_prefix(epoch_challenge) {
+            Ok(input_prefix) => input_prefix,
+            Err(error) => return prover_solutions.iter().map(|_| Err(anyhow!("{error}"))).collect(),
+        };
+
+        // Verify each prover solution.
+        let verifying_key = self.coinbase_verifying_key();
+        cfg_iter!(prover_solutions)
+            .map(|prover_solution| {
+                prover_solution
+                    .verify_and_target_with_input_prefix(verifying_key, epoch_challenge, &input_prefix, proof_target)
+                    .map(|(is_valid, _)| is_valid)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the given partial solution meets the given proof target, and its commitment
+    /// is not the identity, without verifying the proof of the solution.
+    ///
+    /// This is a necessary, but *not* sufficient, condition for the solution to be valid, which is cheap
+    /// enough for relays to filter solutions before forwarding them. Full verification (e.g. `ProverSolution::verify`)
+    /// is still required, before a solution is included in a block.
+    ///
+    /// Note: The target is derived from the puzzle commitment alone, and thus does not depend on the epoch challenge.
+    pub fn verify_target_only(partial_solution: &PartialSolution<N>, proof_target: u64) -> Result<bool> {
+        // Ensure the commitment is not the identity, as in `ProverSolution::verify`.
+        if partial_solution.commitment().is_identity() {
+            return Ok(false);
+        }
+        // Ensure the partial solution meets the proof target.
+        Ok(partial_solution.to_target()? >= proof_target)
+    }
+
+    /// Returns `true` if the given proof shows that the polynomial committed to in `commitment`
+    /// evaluates to `value` at `point`, under the given coinbase verifying key.
+    ///
+    /// This is the bare KZG opening check, and does *not* check any puzzle-specific conditions,
+    /// such as the proof target, or whether the proof is non-hiding.
+    pub fn verify_opening(
+        verifying_key: &CoinbaseVerifyingKey<N>,
+        commitment: &KZGCommitment<N::PairingCurve>,
+        point: <N::PairingCurve as PairingEngine>::Fr,
+        value: <N::PairingCurve as PairingEngine>::Fr,
+        proof: &PuzzleProof<N>,
+    ) -> Result<bool> {
+        Ok(KZG10::check(verifying_key, commitment, point, value, proof)?)
+    }
+
+    /// Returns `true` if the coinbase solution is valid, reusing the cached verification result if present.
+    ///
+    /// The verification result is only cached if verification completes without an error.
+    pub fn verify_cached(
+        &self,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+        cache: &mut VerifyCache<N>,
+    ) -> Result<bool> {
+        // Clear the cache if it was used with a different epoch challenge.
+        cache.prepare(epoch_challenge);
+
+        // Return the cached verification result, if it exists.
+        let key = VerifyCache::to_key(coinbase_solution, coinbase_target, proof_target)?;
+        if let Some(is_valid) = cache.get(&key) {
+            return Ok(is_valid);
+        }
+
+        // Verify the coinbase solution, and cache the verification result.
+        let is_valid = self.verify(coinbase_solution, epoch_challenge, coinbase_target, proof_target)?;
+        cache.insert(key, is_valid);
+        Ok(is_valid)
+    }
+
+    /// Returns `true` if the prover solution is valid, along with the target of the prover solution.
+    ///
+    /// The validity semantics match `ProverSolution::verify`.
+    pub fn verify_and_target(
+        &self,
+        prover_solution: &ProverSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<(bool, u64)> {
+        prover_solution.verify_and_target(self.coinbase_verifying_key(), epoch_challenge, proof_target)
     }
 
     /// Returns the coinbase proving key.
@@ -356,6 +813,59 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(coinbase_verifying_key) => coinbase_verifying_key,
         }
     }
+
+    /// Returns the fingerprint of the given coinbase verifying key, which is the BLAKE2b-512 hash
+    /// of the (uncompressed) points of the key, reduced into a field element.
+    ///
+    /// As the prepared points are derived from the other points, they are not included in the hash.
+    /// Thus, nodes can compare the fingerprint of their verifying key against a known-good fingerprint.
+    pub fn verifying_key_fingerprint(verifying_key: &CoinbaseVerifyingKey<N>) -> Result<Field<N>> {
+        // Serialize the points of the verifying key.
+        let mut bytes = Vec::new();
+        verifying_key.g.serialize_uncompressed(&mut bytes)?;
+        verifying_key.gamma_g.serialize_uncompressed(&mut bytes)?;
+        verifying_key.h.serialize_uncompressed(&mut bytes)?;
+        verifying_key.beta_h.serialize_uncompressed(&mut bytes)?;
+
+        // Return the hash of the points.
+        Ok(Field::new(N::Field::from_bytes_le_mod_order(&blake2::Blake2b512::digest(&bytes))))
+    }
+}
+
+#[cfg(any(test, feature = "setup"))]
+impl<N: Network> CoinbasePuzzle<N> {
+    /// Returns a deterministic benchmark setup for the given seed and degree,
+    /// as `( coinbase_puzzle, epoch_challenge, address )`.
+    pub fn bench_setup(seed: u64, degree: u32) -> Result<(Self, EpochChallenge<N>, Address<N>)> {
+        use console::account::PrivateKey;
+        use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+        let rng = &mut StdRng::seed_from_u64(seed);
+
+        // Load the universal SRS, and trim it to the given degree.
+        let max_degree = degree.checked_add(1).and_then(u32::checked_next_power_of_two);
+        let max_degree = max_degree.ok_or_else(|| anyhow!("Degree is too large"))?;
+        let universal_srs = Self::setup(PuzzleConfig { degree: max_degree })?;
+        let puzzle = Self::trim(&universal_srs, PuzzleConfig { degree })?;
+
+        // Sample the epoch challenge and the prover address.
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree)?;
+        let address = Address::try_from(PrivateKey::new(rng)?)?;
+
+        Ok((puzzle, epoch_challenge, address))
+    }
+
+    /// Returns the time elapsed to compute `iterations` prover solutions over the fixed nonces `0..iterations`,
+    /// for the deterministic benchmark setup of the given seed and degree. The setup itself is not timed.
+    pub fn bench_prove(seed: u64, degree: u32, iterations: usize) -> Result<std::time::Duration> {
+        let (puzzle, epoch_challenge, address) = Self::bench_setup(seed, degree)?;
+
+        let timer = std::time::Instant::now();
+        for nonce in 0..iterations as u64 {
+            puzzle.prove(&epoch_challenge, address, nonce, None)?;
+        }
+        Ok(timer.elapsed())
+    }
 }
 
 impl<N: Network> CoinbasePuzzle<N> {
@@ -381,14 +891,42 @@ impl<N: Network> CoinbasePuzzle<N> {
         address: Address<N>,
         nonce: u64,
     ) -> Result<DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>> {
+        let input_prefix = Self::prover_polynomial_input_prefix(epoch_challenge)?;
+        let input_suffix = Self::prover_polynomial_input_suffix(address, nonce)?;
+        Ok(Self::prover_polynomial_with_input(&input_prefix, &input_suffix, epoch_challenge.degree()))
+    }
+
+    /// Returns the prover polynomial input prefix, defined as `( epoch_number || epoch_block_hash )`.
+    pub(crate) fn prover_polynomial_input_prefix(epoch_challenge: &EpochChallenge<N>) -> Result<[u8; 36]> {
+        #[cfg(test)]
+        NUM_INPUT_PREFIXES.with(|count| count.set(count.get() + 1));
+
+        let mut bytes = [0u8; 36];
+        bytes[..4].copy_from_slice(&epoch_challenge.epoch_number().to_bytes_le()?);
+        bytes[4..].copy_from_slice(&epoch_challenge.epoch_block_hash().to_bytes_le()?);
+        Ok(bytes)
+    }
+
+    /// Returns the prover polynomial input suffix, defined as `( address || nonce )`.
+    fn prover_polynomial_input_suffix(address: Address<N>, nonce: u64) -> Result<[u8; 40]> {
+        let mut bytes = [0u8; 40];
+        bytes[..32].copy_from_slice(&address.to_bytes_le()?);
+        bytes[32..].copy_from_slice(&nonce.to_le_bytes());
+        Ok(bytes)
+    }
+
+    /// Returns the prover polynomial for the coinbase puzzle, for the given input prefix and suffix.
+    fn prover_polynomial_with_input(
+        input_prefix: &[u8; 36],
+        input_suffix: &[u8; 40],
+        degree: u32,
+    ) -> DensePolynomial<<N::PairingCurve as PairingEngine>::Fr> {
         let input = {
             let mut bytes = [0u8; 76];
-            bytes[..4].copy_from_slice(&epoch_challenge.epoch_number().to_bytes_le()?);
-            bytes[4..36].copy_from_slice(&epoch_challenge.epoch_block_hash().to_bytes_le()?);
-            bytes[36..68].copy_from_slice(&address.to_bytes_le()?);
-            bytes[68..].copy_from_slice(&nonce.to_le_bytes());
+            bytes[..36].copy_from_slice(input_prefix);
+            bytes[36..].copy_from_slice(input_suffix);
             bytes
         };
-        Ok(hash_to_polynomial::<<N::PairingCurve as PairingEngine>::Fr>(&input, epoch_challenge.degree()))
+        hash_to_polynomial::<<N::PairingCurve as PairingEngine>::Fr>(&input, degree)
     }
 }
@@ -18,7 +18,8 @@ use super::*;
 use console::{account::*, network::Testnet3};
 use snarkvm_utilities::Uniform;
 
-use rand::RngCore;
+use rand::{seq::SliceRandom, Rng, RngCore};
+use std::{cmp::Reverse, collections::HashSet};
 
 const ITERATIONS: u64 = 100;
 
@@ -85,6 +86,67 @@ fn test_prover_solution_minimum_target() {
     }
 }
 
+#[test]
+fn test_prover_solution_is_not_malleable() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let verifying_key = puzzle.coinbase_verifying_key();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let nonce = u64::rand(&mut rng);
+    let solution = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+    assert!(solution.verify(verifying_key, &epoch_challenge, 0u64).unwrap());
+
+    // Ensure the proof is deterministic, as it is uniquely determined by the commitment and the challenge point.
+    assert_eq!(solution, puzzle.prove(&epoch_challenge, address, nonce, None).unwrap());
+
+    // Ensure a different opening of the same commitment fails.
+    let partial_solution = PartialSolution::new(solution.address(), solution.nonce(), solution.commitment());
+    let candidate =
+        ProverSolution::new(partial_solution, PuzzleProof::<Testnet3> { w: verifying_key.g, random_v: None });
+    assert!(!candidate.verify(verifying_key, &epoch_challenge, 0u64).unwrap());
+
+    // Ensure a blinded (hiding) proof fails.
+    let candidate = ProverSolution::new(partial_solution, PuzzleProof::<Testnet3> {
+        w: solution.proof().w,
+        random_v: Some(rng.gen()),
+    });
+    assert!(!candidate.verify(verifying_key, &epoch_challenge, 0u64).unwrap());
+}
+
+#[test]
+fn test_verify_rejects_identity_commitment() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let verifying_key = puzzle.coinbase_verifying_key();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let solution = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+    assert!(!solution.commitment().is_identity());
+
+    // Construct a solution with an identity commitment.
+    let partial_solution = PartialSolution::new(address, solution.nonce(), PuzzleCommitment::<Testnet3>::default());
+    assert!(partial_solution.commitment().is_identity());
+    let candidate = ProverSolution::new(partial_solution, *solution.proof());
+
+    // Ensure the prover solution and the coinbase solution are rejected.
+    assert!(candidate.verify(verifying_key, &epoch_challenge, 0u64).is_err());
+    let coinbase_solution = CoinbaseSolution::new(vec![partial_solution], *solution.proof());
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).is_err());
+}
+
 #[test]
 fn test_edge_case_for_degree() {
     let mut rng = rand::thread_rng();
@@ -108,3 +170,815 @@ fn test_edge_case_for_degree() {
     let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[prover_solution]).unwrap();
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
 }
+
+#[test]
+fn test_prove_with_address_state() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    for log_degree in 5..10 {
+        let degree = (1 << log_degree) - 1;
+        let config = PuzzleConfig { degree };
+        let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, config).unwrap();
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+
+        // Prepare the address state once.
+        let address_state = puzzle.prepare_address(&epoch_challenge, address).unwrap();
+        assert_eq!(&epoch_challenge, address_state.epoch_challenge());
+        assert_eq!(address, address_state.address());
+
+        for _ in 0..ITERATIONS {
+            let nonce = u64::rand(&mut rng);
+
+            // Ensure the prepared address state yields the same solution.
+            let expected = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+            let candidate = puzzle.prove_with_address_state(&address_state, nonce, None).unwrap();
+            assert_eq!(expected, candidate);
+            assert!(candidate.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0u64).unwrap());
+        }
+
+        // Ensure the minimum proof target is enforced.
+        assert!(puzzle.prove_with_address_state(&address_state, u64::rand(&mut rng), Some(u64::MAX)).is_err());
+    }
+}
+
+#[test]
+fn test_prove_multi() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let config = PuzzleConfig { degree };
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, config).unwrap();
+
+    // Sample the old and new epoch challenges.
+    let epoch_number = rng.next_u32() >> 1;
+    let epoch_challenges = [
+        EpochChallenge::new(epoch_number, Default::default(), degree).unwrap(),
+        EpochChallenge::new(epoch_number + 1, Default::default(), degree).unwrap(),
+    ];
+
+    for _ in 0..ITERATIONS {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        let nonce = u64::rand(&mut rng);
+
+        // Ensure each prover solution equals the corresponding single prover solution.
+        let candidates = puzzle.prove_multi(&epoch_challenges, address, nonce);
+        assert_eq!(epoch_challenges.len(), candidates.len());
+        for (epoch_challenge, candidate) in epoch_challenges.iter().zip(candidates) {
+            let expected = puzzle.prove(epoch_challenge, address, nonce, None).unwrap();
+            assert_eq!(expected, candidate.unwrap());
+        }
+    }
+
+    // Ensure no epoch challenges yield no prover solutions.
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    assert!(puzzle.prove_multi(&[], address, u64::rand(&mut rng)).is_empty());
+
+    // Ensure a verifier fails to prove against each epoch challenge.
+    let verifier = CoinbasePuzzle::<Testnet3>::Verifier(Arc::new(puzzle.coinbase_verifying_key().clone()));
+    let candidates = verifier.prove_multi(&epoch_challenges, address, u64::rand(&mut rng));
+    assert_eq!(epoch_challenges.len(), candidates.len());
+    assert!(candidates.iter().all(|candidate| candidate.is_err()));
+}
+
+#[test]
+fn test_accumulate_in_canonical_order() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Generate the prover solutions.
+    let mut solutions = (0..10)
+        .map(|_| {
+            let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+            let address = Address::try_from(private_key).unwrap();
+            puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Accumulate the prover solutions.
+    let expected = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    assert!(expected.is_canonical());
+    assert!(puzzle.verify(&expected, &epoch_challenge, 0u64, 0u64).unwrap());
+    let expected_bytes = expected.to_bytes_le().unwrap();
+
+    // Ensure the coinbase solution is byte-identical regardless of the input order.
+    for _ in 0..5 {
+        solutions.shuffle(&mut rng);
+        let candidate = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+        assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
+        assert!(puzzle.verify(&candidate, &epoch_challenge, 0u64, 0u64).unwrap());
+    }
+}
+
+#[test]
+fn test_verify_and_target() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    for _ in 0..ITERATIONS {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+
+        // Generate two prover solutions.
+        let solution = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+        let other_solution = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+
+        // Ensure a valid solution returns its target.
+        let (is_valid, target) = puzzle.verify_and_target(&solution, &epoch_challenge, 0u64).unwrap();
+        assert!(is_valid);
+        assert_eq!(solution.to_target().unwrap(), target);
+        assert_eq!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0u64).unwrap(), is_valid);
+
+        // Ensure an invalid proof returns its target alongside `false`.
+        let invalid_solution = ProverSolution::new(
+            PartialSolution::new(solution.address(), solution.nonce(), solution.commitment()),
+            *other_solution.proof(),
+        );
+        let (is_valid, target) = puzzle.verify_and_target(&invalid_solution, &epoch_challenge, 0u64).unwrap();
+        assert!(!is_valid);
+        assert_eq!(solution.to_target().unwrap(), target);
+        assert_eq!(invalid_solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0u64).unwrap(), is_valid);
+
+        // Ensure a solution below the proof target fails, as in `verify`.
+        assert!(puzzle.verify_and_target(&solution, &epoch_challenge, target.saturating_add(1)).is_err());
+    }
+}
+
+#[test]
+fn test_verify_rejects_mixed_epochs() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+
+    let epoch_a = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let epoch_b = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let mut prove = |epoch_challenge: &EpochChallenge<Testnet3>| {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        puzzle.prove(epoch_challenge, address, u64::rand(&mut rng), None).unwrap()
+    };
+    let solutions_a = (0..4).map(|_| prove(&epoch_a)).collect::<Vec<_>>();
+    let solutions_b = (0..4).map(|_| prove(&epoch_b)).collect::<Vec<_>>();
+
+    // Ensure a solution assembled from a single epoch verifies for that epoch only.
+    let consistent = puzzle.accumulate_unchecked(&epoch_a, &solutions_a).unwrap();
+    assert!(puzzle.verify(&consistent, &epoch_a, 0u64, 0u64).unwrap());
+    assert!(!puzzle.verify(&consistent, &epoch_b, 0u64, 0u64).unwrap());
+
+    // Ensure a solution assembled from the prover solutions of two epochs is rejected for either epoch.
+    let mixed_solutions = [&solutions_a[..2], &solutions_b[..2]].concat();
+    for epoch_challenge in [&epoch_a, &epoch_b] {
+        let mixed = puzzle.accumulate_unchecked(epoch_challenge, &mixed_solutions).unwrap();
+        assert!(!puzzle.verify(&mixed, &epoch_a, 0u64, 0u64).unwrap());
+        assert!(!puzzle.verify(&mixed, &epoch_b, 0u64, 0u64).unwrap());
+    }
+}
+
+#[test]
+fn test_verify_epoch_consistency() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let proving_key = puzzle.coinbase_proving_key().unwrap();
+
+    let epoch_a = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let epoch_b = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let mut prove = |epoch_challenge: &EpochChallenge<Testnet3>| {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        puzzle.prove(epoch_challenge, address, u64::rand(&mut rng), None).unwrap()
+    };
+    let solutions_a = (0..4).map(|_| prove(&epoch_a)).collect::<Vec<_>>();
+    let solution_b = prove(&epoch_b);
+
+    // Ensure a solution assembled from a single epoch is consistent with that epoch only.
+    let consistent = puzzle.accumulate_unchecked(&epoch_a, &solutions_a).unwrap();
+    assert!(consistent.verify_epoch_consistency(proving_key, &epoch_a).is_ok());
+    assert!(consistent.verify_epoch_consistency(proving_key, &epoch_b).is_err());
+
+    // Ensure a solution with a prover solution from another epoch is rejected, naming its commitment.
+    let mixed = puzzle.accumulate_unchecked(&epoch_a, &[&solutions_a[..3], &[solution_b]].concat()).unwrap();
+    let error = mixed.verify_epoch_consistency(proving_key, &epoch_a).unwrap_err();
+    assert!(error.to_string().contains(&solution_b.commitment().to_string()));
+    for solution in &solutions_a[..3] {
+        assert!(!error.to_string().contains(&solution.commitment().to_string()));
+    }
+}
+
+#[test]
+fn test_verify_many() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let verifier = CoinbasePuzzle::<Testnet3>::Verifier(Arc::new(puzzle.coinbase_verifying_key().clone()));
+
+    let epoch_a = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let epoch_b = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Sample prover solutions for both epochs, where the prover solutions for `epoch_b` are invalid for `epoch_a`.
+    let mut prover_solutions = vec![];
+    for i in 0..8 {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        let epoch_challenge = if i % 3 == 0 { &epoch_b } else { &epoch_a };
+        prover_solutions.push(puzzle.prove(epoch_challenge, address, u64::rand(&mut rng), None).unwrap());
+    }
+    prover_solutions.shuffle(&mut rng);
+
+    // Pick a proof target that some of the prover solutions do not meet.
+    let mut targets = prover_solutions.iter().map(|solution| solution.to_target().unwrap()).collect::<Vec<_>>();
+    targets.sort_unstable();
+    let proof_target = targets[targets.len() / 2];
+
+    for puzzle in [&puzzle, &verifier] {
+        for proof_target in [0, proof_target] {
+            let results = puzzle.verify_many(&prover_solutions, &epoch_a, proof_target);
+            assert_eq!(prover_solutions.len(), results.len());
+
+            // Ensure each result matches the result of verifying the prover solution individually.
+            for (prover_solution, result) in prover_solutions.iter().zip(results) {
+                match prover_solution.verify(puzzle.coinbase_verifying_key(), &epoch_a, proof_target) {
+                    Ok(expected) => assert_eq!(expected, result.unwrap()),
+                    Err(error) => assert_eq!(error.to_string(), result.unwrap_err().to_string()),
+                }
+            }
+        }
+    }
+
+    // Ensure every prover solution for `epoch_a` is valid, and every prover solution for `epoch_b` is invalid.
+    let results = puzzle.verify_many(&prover_solutions, &epoch_a, 0);
+    let num_valid = results.into_iter().filter(|result| *result.as_ref().unwrap()).count();
+    assert_eq!(5, num_valid);
+
+    // Ensure an empty list of prover solutions yields no results.
+    assert!(puzzle.verify_many(&[], &epoch_a, 0).is_empty());
+
+    // Ensure the shared setup is computed once, whereas individual verification computes it per prover solution.
+    let num_input_prefixes = count_input_prefixes(|| {
+        puzzle.verify_many(&prover_solutions, &epoch_a, 0);
+    });
+    assert_eq!(1, num_input_prefixes);
+    let num_input_prefixes = count_input_prefixes(|| {
+        for prover_solution in &prover_solutions {
+            let _ = prover_solution.verify(puzzle.coinbase_verifying_key(), &epoch_a, 0);
+        }
+    });
+    assert_eq!(prover_solutions.len(), num_input_prefixes);
+}
+
+/// Returns the number of prover polynomial input prefixes computed by the given function.
+///
+/// Note: The function runs in a single-threaded pool, so that any parallel work runs on the thread of the counter.
+fn count_input_prefixes(f: impl FnOnce() + Send) -> usize {
+    let run = || {
+        let start = NUM_INPUT_PREFIXES.with(|count| count.get());
+        f();
+        NUM_INPUT_PREFIXES.with(|count| count.get()) - start
+    };
+
+    #[cfg(feature = "parallel")]
+    {
+        rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap().install(run)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        run()
+    }
+}
+
+#[test]
+fn test_next_epoch_challenge() {
+    let mut rng = TestRng::default();
+
+    let degree = (1 << 5) - 1;
+    let previous = EpochChallenge::<Testnet3>::new(rng.next_u32() / 2, Default::default(), degree).unwrap();
+    let block_hash = <Testnet3 as Network>::BlockHash::rand(&mut rng);
+
+    // Ensure the next epoch challenge advances the epoch number, and differs from the previous.
+    let next = CoinbasePuzzle::next_epoch_challenge(&previous, block_hash, degree).unwrap();
+    assert_eq!(previous.epoch_number() + 1, next.epoch_number());
+    assert_eq!(block_hash, next.epoch_block_hash());
+    assert_eq!(degree, next.degree());
+    assert_ne!(previous.epoch_polynomial(), next.epoch_polynomial());
+
+    // Ensure the derivation is deterministic.
+    let candidate = CoinbasePuzzle::next_epoch_challenge(&previous, block_hash, degree).unwrap();
+    assert_eq!(next, candidate);
+    assert_eq!(next, EpochChallenge::new(previous.epoch_number() + 1, block_hash, degree).unwrap());
+
+    // Ensure the epoch number cannot overflow.
+    let last = EpochChallenge::<Testnet3>::new(u32::MAX, Default::default(), degree).unwrap();
+    assert!(CoinbasePuzzle::next_epoch_challenge(&last, block_hash, degree).is_err());
+}
+
+#[test]
+fn test_membership() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let proving_key = puzzle.coinbase_proving_key().unwrap();
+    let verifying_key = puzzle.coinbase_verifying_key();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let mut prove = || {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap()
+    };
+    let solutions = (0..4).map(|_| prove()).collect::<Vec<_>>();
+    let non_member = prove();
+    let non_member = PartialSolution::new(non_member.address(), non_member.nonce(), non_member.commitment());
+
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    let accumulator = coinbase_solution.accumulator(proving_key).unwrap();
+
+    // Ensure each included partial solution verifies against its witness.
+    for partial_solution in coinbase_solution.partial_solutions() {
+        let witness = coinbase_solution.membership_witness(proving_key, partial_solution).unwrap();
+        assert!(CoinbaseSolution::verify_membership(verifying_key, &accumulator, partial_solution, &witness).unwrap());
+
+        // Ensure a non-member fails with the witness of a member.
+        assert!(!CoinbaseSolution::verify_membership(verifying_key, &accumulator, &non_member, &witness).unwrap());
+
+        // Ensure a partial solution with the same commitment, but a different nonce, fails.
+        let tampered = PartialSolution::new(
+            partial_solution.address(),
+            partial_solution.nonce().wrapping_add(1),
+            partial_solution.commitment(),
+        );
+        assert!(!CoinbaseSolution::verify_membership(verifying_key, &accumulator, &tampered, &witness).unwrap());
+    }
+
+    // Ensure a witness can not be produced for a non-member.
+    assert!(coinbase_solution.membership_witness(proving_key, &non_member).is_err());
+}
+
+#[test]
+fn test_verify_cached() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let other_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let solutions =
+        (0..4).map(|_| puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap()).collect::<Vec<_>>();
+    let solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+
+    let mut cache = VerifyCache::new(8).unwrap();
+
+    // Ensure the first verification is a cache miss, and the second is a cache hit.
+    assert!(puzzle.verify_cached(&solution, &epoch_challenge, 0u64, 0u64, &mut cache).unwrap());
+    assert_eq!((0, 1), (cache.num_hits(), cache.num_misses()));
+    assert!(puzzle.verify_cached(&solution, &epoch_challenge, 0u64, 0u64, &mut cache).unwrap());
+    assert_eq!((1, 1), (cache.num_hits(), cache.num_misses()));
+    assert_eq!(1, cache.len());
+
+    // Ensure different targets are not served from the cache.
+    assert!(puzzle.verify_cached(&solution, &epoch_challenge, 1u64, 0u64, &mut cache).unwrap());
+    assert_eq!((1, 2), (cache.num_hits(), cache.num_misses()));
+    assert_eq!(2, cache.len());
+
+    // Ensure errors are not cached.
+    assert!(puzzle.verify_cached(&solution, &epoch_challenge, u64::MAX, 0u64, &mut cache).is_err());
+    assert_eq!(2, cache.len());
+
+    // Ensure a different epoch challenge clears the cache.
+    let is_valid = puzzle.verify_cached(&solution, &other_epoch_challenge, 0u64, 0u64, &mut cache).unwrap();
+    assert_eq!(puzzle.verify(&solution, &other_epoch_challenge, 0u64, 0u64).unwrap(), is_valid);
+    assert_eq!(1, cache.len());
+    assert_eq!((1, 4), (cache.num_hits(), cache.num_misses()));
+}
+
+#[test]
+fn test_merge_and_difference() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Generate the prover solutions.
+    let solutions = (0..6)
+        .map(|_| {
+            let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+            let address = Address::try_from(private_key).unwrap();
+            puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let expected_commitments = solutions.iter().map(|solution| solution.commitment()).collect::<HashSet<_>>();
+
+    // Check disjoint sets.
+    let (a, b) = (&solutions[..3], &solutions[3..]);
+    let merged = puzzle.merge_unchecked(&epoch_challenge, a, b, usize::MAX).unwrap();
+    assert!(puzzle.verify(&merged, &epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!(expected_commitments, merged.puzzle_commitments().collect::<HashSet<_>>());
+
+    let solution_a = puzzle.accumulate_unchecked(&epoch_challenge, a).unwrap();
+    let solution_b = puzzle.accumulate_unchecked(&epoch_challenge, b).unwrap();
+    assert_eq!(solution_a.partial_solutions(), &merged.difference(&solution_b)[..]);
+    assert_eq!(solution_b.partial_solutions(), &merged.difference(&solution_a)[..]);
+    assert_eq!(solution_a.partial_solutions(), &solution_a.difference(&solution_b)[..]);
+    assert!(merged.difference(&merged).is_empty());
+
+    // Check overlapping sets.
+    let (a, b) = (&solutions[..4], &solutions[2..]);
+    let merged = puzzle.merge_unchecked(&epoch_challenge, a, b, usize::MAX).unwrap();
+    assert!(puzzle.verify(&merged, &epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!(solutions.len(), merged.len());
+    assert_eq!(expected_commitments, merged.puzzle_commitments().collect::<HashSet<_>>());
+
+    let solution_a = puzzle.accumulate_unchecked(&epoch_challenge, a).unwrap();
+    let solution_b = puzzle.accumulate_unchecked(&epoch_challenge, b).unwrap();
+    assert_eq!(2, solution_a.difference(&solution_b).len());
+    assert_eq!(2, solution_b.difference(&solution_a).len());
+
+    // Check the merged set overflowing `max_count` keeps the highest-target prover solutions.
+    let max_count = 3;
+    let merged = puzzle.merge_unchecked(&epoch_challenge, a, b, max_count).unwrap();
+    assert!(puzzle.verify(&merged, &epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!(max_count, merged.len());
+
+    let mut expected = solutions.clone();
+    expected.sort_by_key(|solution| Reverse(solution.to_target().unwrap()));
+    let minimum_target = expected[max_count - 1].to_target().unwrap();
+    for partial_solution in merged.partial_solutions() {
+        assert!(partial_solution.to_target().unwrap() >= minimum_target);
+    }
+    for solution in &expected[max_count..] {
+        assert!(solution.to_target().unwrap() <= minimum_target);
+    }
+}
+
+#[test]
+fn test_prune_to_size_unchecked() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let solutions =
+        (0..8).map(|_| puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap()).collect::<Vec<_>>();
+
+    // Compute the size of the coinbase solution with all of the prover solutions, and with three prover solutions.
+    let full_size = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap().to_bytes_le().unwrap().len();
+    let max_bytes = full_size - 5 * PARTIAL_SOLUTION_UNVERSIONED_SIZE_IN_BYTES;
+
+    // Ensure the coinbase solution is not pruned if it fits.
+    let (pruned, num_pruned) = puzzle.prune_to_size_unchecked(&epoch_challenge, &solutions, full_size).unwrap();
+    assert_eq!(solutions.len(), pruned.unwrap().len());
+    assert_eq!(0, num_pruned);
+
+    // Ensure the pruned coinbase solution fits, and keeps the highest-target prover solutions.
+    let (pruned, num_pruned) = puzzle.prune_to_size_unchecked(&epoch_challenge, &solutions, max_bytes).unwrap();
+    let pruned = pruned.unwrap();
+    assert_eq!(5, num_pruned);
+    assert_eq!(3, pruned.len());
+    assert!(pruned.to_bytes_le().unwrap().len() <= max_bytes);
+    assert!(pruned.is_canonical());
+    assert!(puzzle.verify(&pruned, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    let mut expected = solutions.clone();
+    expected.sort_by_key(|solution| Reverse(solution.to_target().unwrap()));
+    let minimum_target = expected[2].to_target().unwrap();
+    for partial_solution in pruned.partial_solutions() {
+        assert!(partial_solution.to_target().unwrap() >= minimum_target);
+    }
+
+    // Ensure one byte less than the budget for three prover solutions keeps two prover solutions.
+    let (pruned, num_pruned) = puzzle.prune_to_size_unchecked(&epoch_challenge, &solutions, max_bytes - 1).unwrap();
+    assert_eq!(2, pruned.unwrap().len());
+    assert_eq!(6, num_pruned);
+
+    // Ensure no coinbase solution is produced if not even one prover solution fits.
+    let min_bytes = full_size - 7 * PARTIAL_SOLUTION_UNVERSIONED_SIZE_IN_BYTES;
+    let (pruned, num_pruned) = puzzle.prune_to_size_unchecked(&epoch_challenge, &solutions, min_bytes - 1).unwrap();
+    assert!(pruned.is_none());
+    assert_eq!(solutions.len(), num_pruned);
+}
+
+#[test]
+fn test_save_and_load_keys() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let proving_key = puzzle.coinbase_proving_key().unwrap();
+    let verifying_key = puzzle.coinbase_verifying_key();
+
+    // Save the keys.
+    let mut bytes = Vec::new();
+    CoinbasePuzzle::save_keys(proving_key, verifying_key, &mut bytes).unwrap();
+
+    // Load the keys, and ensure they match.
+    let (candidate_proving_key, candidate_verifying_key) = CoinbasePuzzle::<Testnet3>::load_keys(&bytes[..]).unwrap();
+    assert_eq!(proving_key.lagrange_basis_at_beta_g, candidate_proving_key.lagrange_basis_at_beta_g);
+    assert_eq!(proving_key.product_domain, candidate_proving_key.product_domain);
+    assert_eq!(proving_key.product_domain_elements, candidate_proving_key.product_domain_elements);
+    assert_eq!(proving_key.verifying_key, candidate_proving_key.verifying_key);
+    assert_eq!(*verifying_key, candidate_verifying_key);
+
+    // Ensure the loaded keys produce valid solutions.
+    let candidate_puzzle = CoinbasePuzzle::Prover(Arc::new(candidate_proving_key));
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let solution = candidate_puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+    assert!(solution.verify(verifying_key, &epoch_challenge, 0u64).unwrap());
+
+    // Ensure flipping any single byte is detected.
+    for _ in 0..ITERATIONS {
+        let mut corrupt_bytes = bytes.clone();
+        let index = rng.gen_range(0..corrupt_bytes.len());
+        corrupt_bytes[index] ^= 1 << rng.gen_range(0..8);
+        assert!(CoinbasePuzzle::<Testnet3>::load_keys(&corrupt_bytes[..]).is_err());
+    }
+
+    // Ensure truncated keys are rejected.
+    assert!(CoinbasePuzzle::<Testnet3>::load_keys(&bytes[..bytes.len() - 1]).is_err());
+    assert!(CoinbasePuzzle::<Testnet3>::load_keys(&bytes[..16]).is_err());
+}
+
+#[test]
+fn test_verifying_key_fingerprint() {
+    let mut rng = TestRng::default();
+
+    let degree = (1 << 5) - 1;
+    let (_, verifying_key) = CoinbasePuzzle::<Testnet3>::setup_from_seed(rng.gen(), degree).unwrap();
+    let fingerprint = CoinbasePuzzle::verifying_key_fingerprint(&verifying_key).unwrap();
+
+    // Ensure two deserialized copies of the key are equal, and share a fingerprint.
+    let bytes = verifying_key.to_bytes_le().unwrap();
+    let candidate_a = CoinbaseVerifyingKey::<Testnet3>::read_le(&bytes[..]).unwrap();
+    let candidate_b = CoinbaseVerifyingKey::<Testnet3>::read_le(&bytes[..]).unwrap();
+    assert_eq!(candidate_a, candidate_b);
+    assert_eq!(verifying_key, candidate_a);
+    assert_eq!(fingerprint, CoinbasePuzzle::verifying_key_fingerprint(&candidate_a).unwrap());
+    assert_eq!(fingerprint, CoinbasePuzzle::verifying_key_fingerprint(&candidate_b).unwrap());
+
+    // Ensure a modified key differs, and has a different fingerprint.
+    let mut modified = candidate_a;
+    modified.beta_h = modified.h;
+    assert_ne!(verifying_key, modified);
+    assert_ne!(fingerprint, CoinbasePuzzle::verifying_key_fingerprint(&modified).unwrap());
+
+    // Ensure a key from a different setup has a different fingerprint.
+    let (_, other_verifying_key) = CoinbasePuzzle::<Testnet3>::setup_from_seed(rng.gen(), degree).unwrap();
+    assert_ne!(fingerprint, CoinbasePuzzle::verifying_key_fingerprint(&other_verifying_key).unwrap());
+}
+
+#[test]
+fn test_setup_from_seed() {
+    let mut rng = TestRng::default();
+
+    let degree = (1 << 5) - 1;
+    let seed: [u8; 32] = rng.gen();
+
+    // Ensure the same seed produces the same keys.
+    let (proving_key, verifying_key) = CoinbasePuzzle::<Testnet3>::setup_from_seed(seed, degree).unwrap();
+    let (candidate_proving_key, candidate_verifying_key) =
+        CoinbasePuzzle::<Testnet3>::setup_from_seed(seed, degree).unwrap();
+    assert_eq!(proving_key.lagrange_basis_at_beta_g, candidate_proving_key.lagrange_basis_at_beta_g);
+    assert_eq!(proving_key.product_domain, candidate_proving_key.product_domain);
+    assert_eq!(proving_key.verifying_key, candidate_proving_key.verifying_key);
+    assert_eq!(verifying_key, candidate_verifying_key);
+    assert_eq!(proving_key.verifying_key, verifying_key);
+
+    // Ensure a different seed produces different keys.
+    let (other_proving_key, other_verifying_key) =
+        CoinbasePuzzle::<Testnet3>::setup_from_seed(rng.gen(), degree).unwrap();
+    assert_ne!(proving_key.lagrange_basis_at_beta_g, other_proving_key.lagrange_basis_at_beta_g);
+    assert_ne!(verifying_key, other_verifying_key);
+
+    // Ensure the keys produce valid solutions.
+    let puzzle = CoinbasePuzzle::Prover(Arc::new(proving_key));
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree as u32).unwrap();
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let solutions =
+        (0..4).map(|_| puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap()).collect::<Vec<_>>();
+    for solution in &solutions {
+        assert!(solution.verify(&verifying_key, &epoch_challenge, 0u64).unwrap());
+        assert!(!solution.verify(&other_verifying_key, &epoch_challenge, 0u64).unwrap());
+    }
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+}
+
+#[test]
+#[ignore]
+fn test_bench_prove() {
+    let degree = (1 << 13) - 1;
+
+    // Ensure the deterministic setup is reproducible.
+    let (_, epoch_challenge_a, address_a) = CoinbasePuzzle::<Testnet3>::bench_setup(0, degree).unwrap();
+    let (_, epoch_challenge_b, address_b) = CoinbasePuzzle::<Testnet3>::bench_setup(0, degree).unwrap();
+    assert_eq!(epoch_challenge_a, epoch_challenge_b);
+    assert_eq!(address_a, address_b);
+
+    // Ensure the harness runs and measures a nonzero duration.
+    let elapsed = CoinbasePuzzle::<Testnet3>::bench_prove(0, degree, 10).unwrap();
+    assert!(elapsed > std::time::Duration::ZERO);
+}
+
+#[test]
+fn test_verify_opening() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let verifying_key = puzzle.coinbase_verifying_key();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let solution = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+
+    // Compute the opening of the solution commitment at its challenge point.
+    let commitment = solution.commitment();
+    let point = hash_commitment(&commitment).unwrap();
+    let value = solution.to_prover_polynomial(&epoch_challenge).unwrap().evaluate(point)
+        * epoch_challenge.epoch_polynomial().evaluate(point);
+
+    // Ensure the opening is valid.
+    assert!(CoinbasePuzzle::verify_opening(verifying_key, &commitment, point, value, solution.proof()).unwrap());
+
+    // Ensure a tampered value is rejected.
+    let tampered_value = value + value;
+    assert!(
+        !CoinbasePuzzle::verify_opening(verifying_key, &commitment, point, tampered_value, solution.proof()).unwrap()
+    );
+
+    // Ensure a tampered point is rejected.
+    let tampered_point = point + point;
+    assert!(
+        !CoinbasePuzzle::verify_opening(verifying_key, &commitment, tampered_point, value, solution.proof()).unwrap()
+    );
+
+    // Ensure a tampered proof is rejected.
+    let other = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+    assert!(!CoinbasePuzzle::verify_opening(verifying_key, &commitment, point, value, other.proof()).unwrap());
+}
+
+#[test]
+fn test_coinbase_solution_builder_minimum_target() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let mut solutions =
+        (0..8).map(|_| puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap()).collect::<Vec<_>>();
+    solutions.sort_by_key(|solution| solution.to_target().unwrap());
+
+    // Ensure the minimum proof target defaults to 0, and accepts every solution.
+    let mut builder = CoinbaseSolutionBuilder::new();
+    assert_eq!(0, builder.minimum_target());
+    for solution in &solutions {
+        builder.add(*solution).unwrap();
+    }
+    let candidate = builder.build(&puzzle, &epoch_challenge).unwrap();
+    assert_eq!(candidate, puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap());
+    assert!(puzzle.verify(&candidate, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    // Set the minimum proof target to the target of the median solution.
+    let minimum_target = solutions[solutions.len() / 2].to_target().unwrap();
+    let mut builder = CoinbaseSolutionBuilder::new().with_minimum_target(minimum_target).unwrap();
+    for solution in &solutions {
+        match solution.to_target().unwrap() >= minimum_target {
+            // Ensure solutions at or above the minimum proof target are accepted.
+            true => builder.add(*solution).unwrap(),
+            // Ensure solutions below the minimum proof target are rejected, naming the commitment.
+            false => {
+                let error = builder.add(*solution).unwrap_err();
+                assert!(error.to_string().contains(&solution.commitment().to_string()));
+            }
+        }
+    }
+    assert!(builder.prover_solutions().iter().all(|solution| solution.to_target().unwrap() >= minimum_target));
+    assert!(!builder.prover_solutions().is_empty());
+
+    // Ensure raising the minimum proof target above an added solution is rejected.
+    let maximum_target = solutions.last().unwrap().to_target().unwrap();
+    if maximum_target > minimum_target {
+        assert!(builder.with_minimum_target(maximum_target).is_err());
+    }
+}
+
+#[test]
+fn test_verify_target_only() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+
+    for _ in 0..ITERATIONS {
+        let solution = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+        let partial_solution = PartialSolution::new(solution.address(), solution.nonce(), solution.commitment());
+        let target = solution.to_target().unwrap();
+
+        // Ensure a solution at or below its own target passes, and agrees with full verification.
+        for proof_target in [0, target / 2, target] {
+            assert!(CoinbasePuzzle::verify_target_only(&partial_solution, proof_target).unwrap());
+            assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, proof_target).unwrap());
+        }
+
+        // Ensure a solution below the proof target fails.
+        if let Some(proof_target) = target.checked_add(1) {
+            assert!(!CoinbasePuzzle::verify_target_only(&partial_solution, proof_target).unwrap());
+            assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, proof_target).is_err());
+        }
+
+        // Ensure the proof is not checked, as a solution with an invalid opening that meets the target passes,
+        // while full verification rejects it in the pairing check.
+        let forged = ProverSolution::new(partial_solution, PuzzleProof::<Testnet3> {
+            w: puzzle.coinbase_verifying_key().g,
+            random_v: None,
+        });
+        assert!(!forged.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, target).unwrap());
+        assert!(CoinbasePuzzle::verify_target_only(&partial_solution, target).unwrap());
+    }
+
+    // Ensure a commitment that was not produced by the puzzle passes, as long as it meets the target.
+    let commitment = PuzzleCommitment::<Testnet3>::new(KZGCommitment(rng.gen()));
+    let forged = PartialSolution::new(address, u64::rand(&mut rng), commitment);
+    assert!(CoinbasePuzzle::verify_target_only(&forged, forged.to_target().unwrap()).unwrap());
+
+    // Ensure an identity commitment fails, even with a proof target of zero.
+    let identity = PartialSolution::new(address, u64::rand(&mut rng), PuzzleCommitment::<Testnet3>::default());
+    assert!(!CoinbasePuzzle::verify_target_only(&identity, 0).unwrap());
+}